@@ -0,0 +1,188 @@
+//! Example consumer of the pump CPI surface.
+//!
+//! Each authority gets a vault PDA that trades on pump in its own name: the vault signs
+//! the `swap` CPI, pays for its pump position, and holds tokens in a program-owned
+//! (non-associated) token account.
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use pump::{
+    cpi::accounts::Swap as PumpSwap,
+    instructions::SwapResult,
+    program::Pump,
+    state::{CurveConfiguration, LiquidityPool, UserPosition},
+};
+
+declare_id!("DW5aH14fT2BtocW29tjAUtJ2CnX325vcPmUcJYhdykMk");
+
+pub const VAULT_SEED: &str = "vault";
+pub const VAULT_TOKENS_SEED: &str = "vault_tokens";
+
+/// Event emitted with the amounts pump reported back for a vault trade
+#[event]
+pub struct VaultSwapped {
+    pub authority: Pubkey,
+    pub vault: Pubkey,
+    pub mint: Pubkey,
+    pub style: u64,
+    pub amount_in: u64,
+    pub amount_out: u64,
+    pub paperhand_tax: u64,
+}
+
+#[program]
+pub mod pump_cpi_example {
+    use super::*;
+
+    /// Create the vault's token account for a mint. Fund the vault by sending SOL to
+    /// its address.
+    pub fn open_vault(_ctx: Context<OpenVault>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Buy (style 2) or sell (style 1) on pump with the vault's SOL or tokens
+    pub fn vault_swap(
+        ctx: Context<VaultSwap>,
+        amount: u64,
+        style: u64,
+        min_amount_out: u64,
+    ) -> Result<()> {
+        let authority = ctx.accounts.authority.key();
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            VAULT_SEED.as_bytes(),
+            authority.as_ref(),
+            &[ctx.bumps.vault],
+        ]];
+
+        let result: SwapResult = pump::cpi::swap(
+            CpiContext::new_with_signer(
+                ctx.accounts.pump_program.to_account_info(),
+                PumpSwap {
+                    dex_configuration_account: ctx
+                        .accounts
+                        .dex_configuration_account
+                        .to_account_info(),
+                    pool: ctx.accounts.pool.to_account_info(),
+                    global_account: ctx.accounts.global_account.to_account_info(),
+                    treasury_vault: ctx.accounts.treasury_vault.to_account_info(),
+                    user_position: ctx.accounts.vault_position.to_account_info(),
+                    mint_token_one: ctx.accounts.mint.to_account_info(),
+                    pool_token_account_one: ctx.accounts.pool_token_account.to_account_info(),
+                    user_token_account_one: ctx.accounts.vault_token_account.to_account_info(),
+                    referrer: None,
+                    user: ctx.accounts.vault.to_account_info(),
+                    rent: ctx.accounts.rent.to_account_info(),
+                    system_program: ctx.accounts.system_program.to_account_info(),
+                    token_program: ctx.accounts.token_program.to_account_info(),
+                    pool_quote_token_account: None,
+                    user_quote_token_account: None,
+                    treasury_quote_token_account: None,
+                    user_wsol_account: None,
+                    wsol_temp_account: None,
+                    native_mint: None,
+                },
+                signer_seeds,
+            ),
+            amount,
+            style,
+            min_amount_out,
+            false,
+            None,
+            None,
+            None,
+            false,
+        )?
+        .get();
+
+        emit!(VaultSwapped {
+            authority,
+            vault: ctx.accounts.vault.key(),
+            mint: ctx.accounts.mint.key(),
+            style,
+            amount_in: result.amount_in,
+            amount_out: result.amount_out,
+            paperhand_tax: result.paperhand_tax,
+        });
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct OpenVault<'info> {
+    /// Vault PDA; a plain system account so it can pay through the system program
+    #[account(
+        seeds = [VAULT_SEED.as_bytes(), authority.key().as_ref()],
+        bump,
+    )]
+    pub vault: SystemAccount<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        seeds = [VAULT_TOKENS_SEED.as_bytes(), vault.key().as_ref(), mint.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = vault,
+    )]
+    pub vault_token_account: Box<Account<'info, TokenAccount>>,
+
+    pub mint: Box<Account<'info, Mint>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct VaultSwap<'info> {
+    #[account(
+        mut,
+        seeds = [VAULT_SEED.as_bytes(), authority.key().as_ref()],
+        bump,
+    )]
+    pub vault: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_TOKENS_SEED.as_bytes(), vault.key().as_ref(), mint.key().as_ref()],
+        bump,
+    )]
+    pub vault_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: Created by pump on the vault's first buy
+    #[account(
+        mut,
+        seeds = [UserPosition::SEED_PREFIX.as_bytes(), pool.key().as_ref(), vault.key().as_ref()],
+        bump,
+        seeds::program = pump_program.key(),
+    )]
+    pub vault_position: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub dex_configuration_account: Box<Account<'info, CurveConfiguration>>,
+
+    #[account(mut)]
+    pub pool: Box<Account<'info, LiquidityPool>>,
+
+    /// CHECK: Validated by pump
+    #[account(mut)]
+    pub global_account: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by pump
+    #[account(mut)]
+    pub treasury_vault: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub mint: Box<Account<'info, Mint>>,
+
+    /// CHECK: Validated by pump
+    #[account(mut)]
+    pub pool_token_account: UncheckedAccount<'info>,
+
+    pub authority: Signer<'info>,
+    pub pump_program: Program<'info, Pump>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+}
@@ -1 +1,58 @@
-pub const INITIAL_PRICE: u64 = 600;       // lamports per one token (without decimal)
\ No newline at end of file
+/// Default virtual SOL reserve for new pools (50 SOL)
+pub const DEFAULT_VIRTUAL_SOL: u64 = 50_000_000_000;
+
+/// Bounds for the virtual SOL reserve (1 SOL - 10,000 SOL)
+pub const MIN_VIRTUAL_SOL: u64 = 1_000_000_000;
+pub const MAX_VIRTUAL_SOL: u64 = 10_000_000_000_000;
+
+/// Default delay between queuing a configuration change and being able to apply it (24 hours)
+pub const DEFAULT_CONFIG_TIMELOCK_SECS: i64 = 24 * 60 * 60;
+
+/// Bounds for the configuration timelock delay
+pub const MIN_CONFIG_TIMELOCK_SECS: i64 = 60 * 60;
+pub const MAX_CONFIG_TIMELOCK_SECS: i64 = 30 * 24 * 60 * 60;
+
+/// Default upper bound for a pool's anti-sniper launch window (5 minutes)
+pub const DEFAULT_MAX_PROTECTION_WINDOW_SECS: i64 = 5 * 60;
+
+/// Hard upper bound the admin can raise the launch window limit to (1 day)
+pub const MAX_PROTECTION_WINDOW_SECS: i64 = 24 * 60 * 60;
+
+/// Default lowest per-wallet SOL cap a creator can set during the launch window (0.1 SOL)
+pub const DEFAULT_MIN_PROTECTION_BUY_SOL: u64 = 100_000_000;
+
+/// Furthest in the future a launch can schedule its trading start (30 days)
+pub const MAX_TRADING_START_DELAY_SECS: i64 = 30 * 24 * 60 * 60;
+
+/// Default upper bound for the share of supply a creator can reserve for vesting (10%)
+pub const DEFAULT_MAX_CREATOR_ALLOCATION_BPS: u16 = 1_000;
+
+/// Hard upper bound the admin can raise the creator allocation limit to (20%)
+pub const MAX_CREATOR_ALLOCATION_BPS: u16 = 2_000;
+
+/// Longest vesting schedule a creator allocation can use (4 years)
+pub const MAX_VESTING_DURATION_SECS: i64 = 4 * 365 * 24 * 60 * 60;
+
+/// Longest a creator can lock their own sells after launch (1 year)
+pub const MAX_CREATOR_SELL_LOCK_SECS: i64 = 365 * 24 * 60 * 60;
+
+/// Mint decimals a launch can use
+pub const ALLOWED_DECIMALS: [u8; 2] = [6, 9];
+
+/// Fixed-point scale of curve prices: prices are lamports per whole token times `PRICE_SCALE`
+pub const PRICE_SCALE: u128 = 1_000_000_000;
+
+/// Default bounds for a new curve's starting price (0.001 lamports - 1 SOL per whole token, scaled)
+pub const DEFAULT_MIN_START_PRICE: u64 = 1_000_000;
+pub const DEFAULT_MAX_START_PRICE: u64 = 1_000_000_000_000_000_000;
+
+/// Highest launch fee the admin can configure (10 SOL)
+pub const MAX_LAUNCH_FEE_LAMPORTS: u64 = 10_000_000_000;
+
+/// Seed prefix of the short-lived token account that unwraps a trader's wSOL during `swap`
+pub const WSOL_TEMP_SEED_PREFIX: &str = "wsol_temp";
+
+/// Metaplex metadata length limits, in bytes
+pub const MAX_NAME_LEN: usize = 32;
+pub const MAX_SYMBOL_LEN: usize = 10;
+pub const MAX_URI_LEN: usize = 200;
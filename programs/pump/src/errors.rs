@@ -39,6 +39,12 @@ pub enum CustomError {
     #[msg("Position not initialized: user has no buy history for this pool")]
     PositionNotInitialized,
 
+    #[msg("Position still holds tokens and cannot be closed")]
+    PositionNotEmpty,
+
+    #[msg("Positions cannot be closed during the pool's launch phase")]
+    PositionCloseLocked,
+
     #[msg("Math overflow occurred during calculation")]
     MathOverflow,
 
@@ -53,4 +59,138 @@ pub enum CustomError {
 
     #[msg("Unauthorized: Only admin can perform this action")]
     Unauthorized,
+
+    // Configuration timelock errors
+    #[msg("Configuration change does not update any field")]
+    EmptyConfigChange,
+
+    #[msg("Invalid configuration timelock delay")]
+    InvalidTimelock,
+
+    #[msg("Configuration change timelock has not expired yet")]
+    TimelockNotExpired,
+
+    #[msg("Invalid authority: cannot be the default public key")]
+    InvalidAuthority,
+
+    #[msg("Invalid default virtual SOL reserve")]
+    InvalidVirtualSol,
+
+    #[msg("Minimum start price must not exceed the maximum")]
+    InvalidPriceBounds,
+
+    #[msg("Launch fee exceeds the allowed maximum")]
+    InvalidLaunchFee,
+
+    // Emergency pause errors
+    #[msg("Trading is paused")]
+    TradingPaused,
+
+    #[msg("Buys are paused: only sells are allowed")]
+    BuysPaused,
+
+    #[msg("Unauthorized: Only the pauser or admin can perform this action")]
+    UnauthorizedPauser,
+
+    // Launch protection errors
+    #[msg("Invalid launch protection parameters")]
+    InvalidLaunchProtection,
+
+    #[msg("Per-wallet buy cap for the launch window exceeded")]
+    LaunchBuyCapExceeded,
+
+    #[msg("Price impact exceeds the allowed maximum")]
+    PriceImpactTooHigh,
+
+    // Swap guard errors
+    #[msg("Swap deadline has passed")]
+    DeadlineExceeded,
+
+    #[msg("Swap landed after its maximum slot")]
+    MaxSlotExceeded,
+
+    #[msg("Not enough real SOL in the pool to pay for this sell")]
+    InsufficientPoolLiquidity,
+
+    // Presale errors
+    #[msg("Invalid presale parameters")]
+    InvalidPresale,
+
+    #[msg("Presale is active: public trading has not opened yet")]
+    PresaleActive,
+
+    #[msg("Pool has no active presale")]
+    PresaleNotActive,
+
+    #[msg("Invalid presale allowlist proof")]
+    InvalidPresaleProof,
+
+    #[msg("Presale allocation exceeded")]
+    PresaleAllocationExceeded,
+
+    // Scheduled launch errors
+    #[msg("Trading has not opened yet for this pool")]
+    TradingNotOpen,
+
+    // Fair launch errors
+    #[msg("Fair launch has not been settled yet")]
+    FairLaunchNotSettled,
+
+    #[msg("Pool is not a fair launch")]
+    NotFairLaunch,
+
+    #[msg("Fair launch commitment window is closed")]
+    FairLaunchCommitClosed,
+
+    #[msg("Fair launch commitment window is still open")]
+    FairLaunchCommitOpen,
+
+    #[msg("Invalid fair launch settlement accounts")]
+    InvalidSettlementAccounts,
+
+    // Creator vesting errors
+    #[msg("Invalid creator vesting parameters")]
+    InvalidVesting,
+
+    #[msg("No vested tokens to claim yet")]
+    NothingToClaim,
+
+    // Creator rules errors
+    #[msg("Invalid creator sell rules")]
+    InvalidCreatorRules,
+
+    #[msg("Creator sells are locked")]
+    CreatorSellLocked,
+
+    // Metadata errors
+    #[msg("Only the pool creator can perform this action")]
+    NotPoolCreator,
+
+    #[msg("Token metadata is locked")]
+    MetadataLocked,
+
+    #[msg("The symbol cannot change while the symbol policy requires unique symbols")]
+    SymbolLocked,
+
+    // Referral errors
+    #[msg("A trader cannot refer themselves")]
+    SelfReferral,
+
+    #[msg("No referral fees to claim")]
+    NothingToClaimReferral,
+
+    // Quote asset errors
+    #[msg("Only available on pools quoted in native SOL")]
+    NativeQuoteOnly,
+
+    #[msg("Pools quoted in an SPL token require the quote token accounts")]
+    MissingQuoteAccounts,
+
+    // Wrapped SOL errors
+    #[msg("Paying in wSOL requires the temporary wSOL account and the native mint")]
+    MissingWsolAccounts,
+
+    // Account migration errors
+    #[msg("Account version is newer than this program supports")]
+    UnsupportedAccountVersion,
 }
@@ -5,10 +5,12 @@ use anchor_spl::{
 };
 
 use crate::{
-    state::{LiquidityPool, LiquidityPoolAccount, LiquidityProvider},
+    state::{CurveConfiguration, LiquidityPool, LiquidityPoolAccount, LiquidityProvider},
 };
 
 pub fn add_liquidity(ctx: Context<AddLiquidity>, amount_one: u64, amount_two: u64) -> Result<()> {
+    ctx.accounts.dex_configuration_account.pause_state.ensure_buys_allowed()?;
+
     let pool = &mut ctx.accounts.pool;
 
     let token_one_accounts = (
@@ -34,7 +36,7 @@ pub fn add_liquidity(ctx: Context<AddLiquidity>, amount_one: u64, amount_two: u6
         token_two_accounts,
         amount_one,
         amount_two,
-        &mut *ctx.accounts.liquidity_provider_account,
+        &mut ctx.accounts.liquidity_provider_account,
         &ctx.accounts.user,
         &ctx.accounts.token_program,
     )?;
@@ -44,6 +46,12 @@ pub fn add_liquidity(ctx: Context<AddLiquidity>, amount_one: u64, amount_two: u6
 
 #[derive(Accounts)]
 pub struct AddLiquidity<'info> {
+    #[account(
+        seeds = [CurveConfiguration::SEED.as_bytes()],
+        bump,
+    )]
+    pub dex_configuration_account: Box<Account<'info, CurveConfiguration>>,
+
     #[account(
         init,
         space = LiquidityPool::ACCOUNT_SIZE,
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::CustomError,
+    state::{LiquidityPool, UserPosition},
+};
+
+/// Event emitted when an empty position is closed and its rent returned
+#[event]
+pub struct PositionClosed {
+    pub user: Pubkey,
+    pub pool: Pubkey,
+    pub rent_returned: u64,
+}
+
+/// Close an empty `UserPosition` and return its rent to the owner
+pub fn close_position(ctx: Context<ClosePosition>) -> Result<()> {
+    ctx.accounts.pool.ensure_positions_closable(Clock::get()?.unix_timestamp)?;
+
+    msg!("Closing position: {:?}", ctx.accounts.user_position.key());
+
+    emit!(PositionClosed {
+        user: ctx.accounts.owner.key(),
+        pool: ctx.accounts.pool.key(),
+        rent_returned: ctx.accounts.user_position.to_account_info().lamports(),
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ClosePosition<'info> {
+    #[account(
+        mut,
+        close = owner,
+        seeds = [UserPosition::SEED_PREFIX.as_bytes(), pool.key().as_ref(), owner.key().as_ref()],
+        bump,
+        constraint = user_position.total_tokens == 0 @ CustomError::PositionNotEmpty,
+    )]
+    pub user_position: Box<Account<'info, UserPosition>>,
+
+    #[account(
+        seeds = [LiquidityPool::POOL_SEED_PREFIX.as_bytes(), pool.token_one.as_ref()],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, LiquidityPool>>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+}
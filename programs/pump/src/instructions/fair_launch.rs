@@ -0,0 +1,339 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{self, Mint, Token, TokenAccount},
+};
+
+use crate::{
+    errors::CustomError,
+    instructions::swap::{PositionUpdated, TradeExecuted},
+    state::{CurveConfiguration, LaunchCommitment, LiquidityPool, UserPosition},
+};
+
+/// Event emitted for every fair-launch commitment
+#[event]
+pub struct FairLaunchCommitted {
+    pub user: Pubkey,
+    pub pool: Pubkey,
+    pub amount: u64,
+    pub total_committed: u64,
+}
+
+/// Event emitted when a fair launch is cleared at a single price
+#[event]
+pub struct FairLaunchCleared {
+    pub pool: Pubkey,
+    pub total_committed: u64,
+    pub tokens_sold: u64,
+    pub sol_used: u64,
+    pub timestamp: i64,
+}
+
+/// Commit SOL to a pool's fair-launch auction.
+///
+/// The SOL is escrowed in the global vault until `settle_launch`. The user's position
+/// and token account are created now so settlement can distribute to them.
+pub fn commit_to_fair_launch(ctx: Context<CommitToFairLaunch>, amount: u64) -> Result<()> {
+    if amount == 0 {
+        return err!(CustomError::InvalidAmount);
+    }
+
+    let pool = &mut ctx.accounts.pool;
+    let clock = Clock::get()?;
+
+    ctx.accounts.dex_configuration_account.pause_state.ensure_buys_allowed()?;
+    pool.pause_state.ensure_buys_allowed()?;
+
+    require!(pool.fair_launch.is_enabled(), CustomError::NotFairLaunch);
+    require!(
+        pool.fair_launch.is_committing(clock.unix_timestamp),
+        CustomError::FairLaunchCommitClosed
+    );
+
+    let commitment = &mut ctx.accounts.commitment;
+    if commitment.owner == Pubkey::default() {
+        commitment.pool = pool.key();
+        commitment.owner = ctx.accounts.user.key();
+        commitment.bump = ctx.bumps.commitment;
+    }
+    commitment.sol_committed = commitment.sol_committed
+        .checked_add(amount)
+        .ok_or(CustomError::MathOverflow)?;
+
+    let position = &mut ctx.accounts.user_position;
+    if position.owner == Pubkey::default() {
+        position.set_inner(UserPosition::new(
+            pool.key(),
+            ctx.accounts.user.key(),
+            ctx.bumps.user_position,
+        ));
+    }
+
+    pool.fair_launch.total_committed = pool.fair_launch.total_committed
+        .checked_add(amount)
+        .ok_or(CustomError::MathOverflow)?;
+
+    // Escrow SOL in the global account
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.user.to_account_info(),
+                to: ctx.accounts.global_account.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    emit!(FairLaunchCommitted {
+        user: ctx.accounts.user.key(),
+        pool: pool.key(),
+        amount,
+        total_committed: pool.fair_launch.total_committed,
+    });
+
+    Ok(())
+}
+
+/// Settle a fair launch. Permissionless.
+///
+/// The first call after the commitment window clears the whole auction as a single
+/// buy on the curve (capped at `max_tokens`), so every participant pays the same price.
+/// Each call then settles the commitments passed in `remaining_accounts`, in groups of
+/// `[commitment, user_position, user_token_account, owner]`: tokens are sent to the
+/// owner, the buy is recorded on their position, excess SOL is refunded and the
+/// commitment is closed. Call repeatedly until every commitment is settled.
+pub fn settle_launch<'info>(ctx: Context<'_, '_, 'info, 'info, SettleLaunch<'info>>) -> Result<()> {
+    let clock = Clock::get()?;
+    let pool = &mut ctx.accounts.pool;
+
+    require!(pool.fair_launch.is_enabled(), CustomError::NotFairLaunch);
+    require!(
+        !pool.fair_launch.is_committing(clock.unix_timestamp),
+        CustomError::FairLaunchCommitOpen
+    );
+
+    if !pool.fair_launch.cleared {
+        clear_fair_launch(pool, ctx.accounts.dex_configuration_account.fees, clock.unix_timestamp)?;
+    }
+
+    require!(ctx.remaining_accounts.len().is_multiple_of(4), CustomError::InvalidSettlementAccounts);
+
+    let pool_key = pool.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[b"global", &[ctx.bumps.global_account]]];
+
+    for group in ctx.remaining_accounts.chunks_exact(4) {
+        let [commitment_info, position_info, token_account_info, owner_info] = group else {
+            return err!(CustomError::InvalidSettlementAccounts);
+        };
+
+        let commitment = Account::<LaunchCommitment>::try_from(commitment_info)?;
+        require_keys_eq!(commitment.pool, pool_key, CustomError::InvalidSettlementAccounts);
+        require_keys_eq!(commitment.owner, owner_info.key(), CustomError::InvalidSettlementAccounts);
+
+        let mut position = Account::<UserPosition>::try_from(position_info)?;
+        let (expected_position, _) = Pubkey::find_program_address(
+            &[UserPosition::SEED_PREFIX.as_bytes(), pool_key.as_ref(), owner_info.key.as_ref()],
+            &crate::ID,
+        );
+        require_keys_eq!(position.key(), expected_position, CustomError::InvalidSettlementAccounts);
+
+        let token_account = Account::<TokenAccount>::try_from(token_account_info)?;
+        require_keys_eq!(token_account.mint, pool.token_one, CustomError::InvalidSettlementAccounts);
+        require_keys_eq!(token_account.owner, owner_info.key(), CustomError::InvalidSettlementAccounts);
+
+        let (tokens, sol_spent, refund) = pool.fair_launch.allocation_for(commitment.sol_committed)?;
+
+        if tokens > 0 {
+            token::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    token::Transfer {
+                        from: ctx.accounts.pool_token_account_one.to_account_info(),
+                        to: token_account_info.clone(),
+                        authority: ctx.accounts.global_account.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                tokens,
+            )?;
+        }
+
+        if refund > 0 {
+            system_program::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: ctx.accounts.global_account.to_account_info(),
+                        to: owner_info.clone(),
+                    },
+                    signer_seeds,
+                ),
+                refund,
+            )?;
+        }
+
+        position.record_buy(tokens, sol_spent)?;
+        position.exit(&crate::ID)?;
+        pool.fair_launch.sol_settled = pool.fair_launch.sol_settled
+            .checked_add(commitment.sol_committed)
+            .ok_or(CustomError::MathOverflow)?;
+
+        // Commitment is settled: return its rent to the owner
+        commitment.close(owner_info.clone())?;
+
+        msg!("Settled commitment: {:?} {:?} -> {:?}, refund {:?}", owner_info.key(), sol_spent, tokens, refund);
+
+        emit!(TradeExecuted {
+            user: owner_info.key(),
+            pool: pool_key,
+            side: "buy".to_string(),
+            token_amount: tokens,
+            sol_amount: sol_spent,
+            referrer: None,
+            referral_fee: 0,
+            timestamp: clock.unix_timestamp,
+        });
+
+        emit!(PositionUpdated {
+            user: owner_info.key(),
+            pool: pool_key,
+            total_tokens: position.total_tokens,
+            total_sol: position.total_sol,
+        });
+    }
+
+    Ok(())
+}
+
+/// Execute the whole auction as one buy on the curve at a uniform price
+fn clear_fair_launch(pool: &mut Account<LiquidityPool>, fees_bps: u16, now: i64) -> Result<()> {
+    let total_committed = pool.fair_launch.total_committed;
+
+    let (tokens_sold, sol_used) = if total_committed == 0 {
+        (0, 0)
+    } else {
+        let tokens_out = pool.quote_buy(total_committed, fees_bps)?;
+        if tokens_out <= pool.fair_launch.max_tokens {
+            (tokens_out, total_committed)
+        } else {
+            // Demand exceeds the cap: sell exactly max_tokens and refund the rest pro rata
+            let max_tokens = pool.fair_launch.max_tokens;
+            (max_tokens, pool.quote_buy_exact_out(max_tokens, fees_bps)?)
+        }
+    };
+
+    pool.apply_buy(tokens_sold, sol_used)?;
+    pool.fair_launch.tokens_sold = tokens_sold;
+    pool.fair_launch.sol_used = sol_used;
+    pool.fair_launch.cleared = true;
+
+    msg!("Fair launch cleared: {:?} SOL for {:?} tokens", sol_used, tokens_sold);
+
+    emit!(FairLaunchCleared {
+        pool: pool.key(),
+        total_committed,
+        tokens_sold,
+        sol_used,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CommitToFairLaunch<'info> {
+    #[account(
+        seeds = [CurveConfiguration::SEED.as_bytes()],
+        bump,
+    )]
+    pub dex_configuration_account: Box<Account<'info, CurveConfiguration>>,
+
+    #[account(
+        mut,
+        seeds = [LiquidityPool::POOL_SEED_PREFIX.as_bytes(), mint_token_one.key().as_ref()],
+        bump = pool.bump
+    )]
+    pub pool: Box<Account<'info, LiquidityPool>>,
+
+    /// CHECK: Global SOL vault PDA
+    #[account(
+        mut,
+        seeds = [b"global"],
+        bump,
+    )]
+    pub global_account: AccountInfo<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = LaunchCommitment::ACCOUNT_SIZE,
+        seeds = [LaunchCommitment::SEED_PREFIX.as_bytes(), pool.key().as_ref(), user.key().as_ref()],
+        bump,
+    )]
+    pub commitment: Box<Account<'info, LaunchCommitment>>,
+
+    /// User position, created now so settlement can record the buy
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = UserPosition::ACCOUNT_SIZE,
+        seeds = [UserPosition::SEED_PREFIX.as_bytes(), pool.key().as_ref(), user.key().as_ref()],
+        bump,
+    )]
+    pub user_position: Box<Account<'info, UserPosition>>,
+
+    pub mint_token_one: Box<Account<'info, Mint>>,
+
+    /// User token account, created now so settlement can distribute to it
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = mint_token_one,
+        associated_token::authority = user,
+    )]
+    pub user_token_account_one: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+#[derive(Accounts)]
+pub struct SettleLaunch<'info> {
+    #[account(
+        seeds = [CurveConfiguration::SEED.as_bytes()],
+        bump,
+    )]
+    pub dex_configuration_account: Box<Account<'info, CurveConfiguration>>,
+
+    #[account(
+        mut,
+        seeds = [LiquidityPool::POOL_SEED_PREFIX.as_bytes(), mint_token_one.key().as_ref()],
+        bump = pool.bump
+    )]
+    pub pool: Box<Account<'info, LiquidityPool>>,
+
+    /// CHECK: Global SOL vault PDA
+    #[account(
+        mut,
+        seeds = [b"global"],
+        bump,
+    )]
+    pub global_account: AccountInfo<'info>,
+
+    pub mint_token_one: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint_token_one,
+        associated_token::authority = global_account
+    )]
+    pub pool_token_account_one: Box<Account<'info, TokenAccount>>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+}
@@ -6,10 +6,17 @@ use anchor_spl::{
         mpl_token_metadata::types::DataV2,
         CreateMetadataAccountsV3,
     },
-    token::{self, Mint, MintTo},
+    token::{self, spl_token::instruction::AuthorityType, Mint, MintTo, SetAuthority, TokenAccount},
 };
 
-use crate::state::{CurveConfiguration, LiquidityPool};
+use crate::{
+    consts::{ALLOWED_DECIMALS, MAX_NAME_LEN, MAX_SYMBOL_LEN, MAX_TRADING_START_DELAY_SECS, MAX_URI_LEN},
+    instructions::swap::{PositionUpdated, TradeExecuted},
+    state::{
+        CreatorRules, CreatorVesting, CurveConfiguration, LaunchFeeWaiver, LaunchRecord, FairLaunch, FairLaunchParams, LaunchProtection,
+        LaunchProtectionParams, LiquidityPool, PauseState, Presale, QuoteAsset, UserPosition, VestingParams,
+    },
+};
 
 /// Event emitted when a new token is launched
 #[event]
@@ -20,61 +27,223 @@ pub struct TokenLaunched {
     pub name: String,
     pub symbol: String,
     pub uri: String,
+    /// Minted supply in base units (whole tokens * 10^decimals)
     pub initial_supply: u64,
+    pub decimals: u8,
+    /// Quote mint, or the System Program ID for native SOL
+    pub quote_mint: Pubkey,
+    /// Curve starting price, in quote base units (lamports for SOL) per whole token
+    /// scaled by `PRICE_SCALE`
+    pub start_price: u64,
+    /// When public `swap` trading opens
+    pub trading_opens_at: i64,
+    /// Tokens reserved for the creator in the vesting escrow (0 when none)
+    pub creator_allocation: u64,
+    /// Vesting schedule of the creator allocation (all 0 when none)
+    pub vesting_cliff_ts: i64,
+    pub vesting_end_ts: i64,
+    /// Final mint authorities, both `None` once the launch completes (fixed supply, no freezes)
+    pub mint_authority: Option<Pubkey>,
+    pub freeze_authority: Option<Pubkey>,
+    /// Launch fee paid to the treasury (0 when disabled or waived)
+    pub launch_fee: u64,
     pub timestamp: i64,
 }
 
 /// Launch a new token with Paper Hand Tax enabled
 /// 
 /// This instruction:
-/// 1. Creates a new SPL Token Mint
+/// 1. Creates a new SPL Token Mint without a freeze authority
 /// 2. Creates Metaplex Metadata (name, symbol, image)
 /// 3. Initializes the Bonding Curve Pool
 /// 4. Mints initial supply to the pool
 /// 5. Revokes mint authority (fixed supply)
+/// 6. Optionally executes the creator's "dev buy" atomically
+/// 7. Optionally enables anti-sniper limits for the first seconds of trading
+/// 8. Optionally starts with an allowlisted presale before public trading
+/// 9. Optionally schedules the trading start for a later time
+/// 10. Optionally opens with a batch-auction fair launch instead of first-come trading
+/// 11. Optionally reserves a share of supply for the creator in a vesting escrow
+/// 12. Records the creator, with optional stricter sell rules for them
+/// 13. Charges the configured launch fee unless the creator holds a waiver
+/// 14. Optionally quotes the curve in a registered SPL token instead of SOL
+#[allow(clippy::too_many_arguments)]
 pub fn launch(
-    ctx: Context<Launch>,
+    mut ctx: Context<Launch>,
     name: String,
     symbol: String,
     uri: String,
-    _decimals: u8,
+    decimals: u8,
     initial_supply: u64,
     initial_sol_reserve: u64,
+    initial_buy_sol: Option<u64>,
+    launch_protection: Option<LaunchProtectionParams>,
+    presale: Option<Presale>,
+    trading_opens_at: Option<i64>,
+    fair_launch: Option<FairLaunchParams>,
+    vesting: Option<VestingParams>,
+    creator_rules: Option<CreatorRules>,
 ) -> Result<()> {
     // Validate inputs first (small stack usage)
-    require!(name.len() <= 32, LaunchError::NameTooLong);
-    require!(symbol.len() <= 10, LaunchError::SymbolTooLong);
-    require!(uri.len() <= 200, LaunchError::UriTooLong);
+    validate_metadata(&name, &symbol, &uri)?;
+    require!(ALLOWED_DECIMALS.contains(&decimals), LaunchError::InvalidDecimals);
     require!(initial_supply > 0, LaunchError::InvalidSupply);
+    // Supply is given in whole tokens; everything below works in base units
+    let initial_supply = initial_supply
+        .checked_mul(10u64.pow(decimals as u32))
+        .ok_or(LaunchError::InvalidSupply)?;
     require!(initial_sol_reserve > 0, LaunchError::InvalidSolReserve);
+    require!(initial_buy_sol != Some(0), LaunchError::InvalidDevBuy);
+
+    // No new pools while the protocol is paused or winding down
+    ctx.accounts.dex_configuration_account.pause_state.ensure_buys_allowed()?;
+
+    if let Some(params) = launch_protection.as_ref() {
+        params.validate(&ctx.accounts.dex_configuration_account)?;
+    }
+
+    let launched_at = Clock::get()?.unix_timestamp;
+    if let Some(presale) = presale.as_ref() {
+        presale.validate(launched_at)?;
+    }
+    if let Some(opens_at) = trading_opens_at {
+        require!(
+            opens_at > launched_at
+                && opens_at - launched_at <= MAX_TRADING_START_DELAY_SECS,
+            LaunchError::InvalidTradingStart
+        );
+    }
+    if let Some(rules) = creator_rules.as_ref() {
+        rules.validate(&ctx.accounts.dex_configuration_account, launched_at)?;
+    }
+    let creator_allocation = match vesting.as_ref() {
+        Some(params) => {
+            params.validate(&ctx.accounts.dex_configuration_account)?;
+            params.allocation(initial_supply)?
+        }
+        None => 0,
+    };
+    // Only the remainder is sold on the curve
+    let curve_supply = initial_supply - creator_allocation;
+    // Presales, fair launches and dev buys only settle in native SOL
+    if ctx.accounts.quote_asset.is_some() {
+        require!(
+            presale.is_none() && fair_launch.is_none() && initial_buy_sol.is_none(),
+            LaunchError::NativeQuoteOnly
+        );
+    }
+    if let Some(params) = fair_launch.as_ref() {
+        // The auction is the opening phase: it replaces the presale and the dev buy
+        require!(
+            presale.is_none() && initial_buy_sol.is_none(),
+            LaunchError::IncompatibleLaunchOptions
+        );
+        require!(
+            params.commit_ends_at > launched_at
+                && params.commit_ends_at - launched_at <= MAX_TRADING_START_DELAY_SECS,
+            LaunchError::InvalidFairLaunch
+        );
+        require!(
+            params.max_tokens > 0 && params.max_tokens <= curve_supply,
+            LaunchError::InvalidFairLaunch
+        );
+    }
 
     msg!("Launching token: {} ({})", name, symbol);
 
     // Call helper functions with #[inline(never)] to use separate stack frames
     create_metadata_helper(&ctx, &name, &symbol, &uri)?;
     
-    // Get virtual SOL from config for better price curves
-    let virtual_sol = ctx.accounts.dex_configuration_account.default_virtual_sol;
+    // Virtual reserve and price bounds come from the config for SOL, from the quote
+    // asset otherwise
+    let config = &ctx.accounts.dex_configuration_account;
+    let (quote_mint, virtual_sol, min_start_price, max_start_price) =
+        match ctx.accounts.quote_asset.as_ref() {
+            Some(quote) => (
+                quote.mint,
+                quote.default_virtual_reserve,
+                quote.min_start_price,
+                quote.max_start_price,
+            ),
+            None => (
+                system_program::ID,
+                config.default_virtual_sol,
+                config.min_start_price,
+                config.max_start_price,
+            ),
+        };
     
     initialize_pool_helper(
         &mut ctx.accounts.pool,
         ctx.accounts.mint.key(),
+        quote_mint,
         ctx.bumps.pool,
-        initial_supply,
+        curve_supply,
         initial_sol_reserve,
         virtual_sol
     )?;
+    // Reject curves whose starting price is unusable (dust-priced or prohibitively expensive)
+    let start_price = ctx.accounts.pool.spot_price(decimals)
+        .map_err(|_| LaunchError::StartPriceOutOfBounds)?;
+    require!(
+        (min_start_price..=max_start_price).contains(&start_price),
+        LaunchError::StartPriceOutOfBounds
+    );
+    ctx.accounts.launch_record.set_inner(LaunchRecord {
+        mint: ctx.accounts.mint.key(),
+        creator: ctx.accounts.creator.key(),
+        symbol: symbol.clone(),
+        launched_at,
+        bump: ctx.bumps.launch_record,
+    });
+    ctx.accounts.pool.creator = ctx.accounts.creator.key();
+    ctx.accounts.pool.creator_rules = creator_rules.unwrap_or_default();
+    if let Some(presale) = presale {
+        ctx.accounts.pool.presale = presale;
+    }
+    if let Some(params) = fair_launch.as_ref() {
+        ctx.accounts.pool.fair_launch = FairLaunch::new(params);
+    }
+    ctx.accounts.pool.trading_opens_at = trading_opens_at.unwrap_or(launched_at);
+    if let Some(params) = launch_protection.as_ref() {
+        // The protection window starts when public trading opens
+        let opens_at = ctx.accounts.pool.public_trading_opens_at();
+        ctx.accounts.pool.launch_protection = LaunchProtection::new(params, opens_at)?;
+    }
     create_pool_token_account_helper(&ctx)?;
-    mint_tokens_helper(&ctx, initial_supply)?;
-    transfer_sol_helper(&ctx, initial_sol_reserve)?;
+    mint_tokens_helper(&ctx, curve_supply)?;
+    if let Some(params) = vesting.as_ref() {
+        vesting_helper(&mut ctx, params, creator_allocation, launched_at, start_price)?;
+    }
+    revoke_mint_authority_helper(&mut ctx)?;
+    if ctx.accounts.quote_asset.is_some() {
+        transfer_quote_reserve_helper(&ctx, initial_sol_reserve)?;
+    } else {
+        transfer_sol_helper(&ctx, initial_sol_reserve)?;
+    }
+    let launch_fee = launch_fee_helper(&ctx)?;
     
     // Initialize LP and emit event
-    finalize_launch_helper(&ctx, initial_supply, name, symbol, uri)?;
+    finalize_launch_helper(&ctx, initial_supply, launch_fee, name, symbol, uri)?;
+
+    // Creator buy in the same instruction, so nobody can snipe in between.
+    // It is not subject to the launch protection limits, which target third-party snipers.
+    if let Some(sol_in) = initial_buy_sol {
+        dev_buy_helper(&mut ctx, sol_in)?;
+    }
 
     msg!("Token launched successfully! Pool: {}", ctx.accounts.pool.key());
     Ok(())
 }
 
+/// Length limits for token metadata, shared with `update_token_metadata`
+pub(crate) fn validate_metadata(name: &str, symbol: &str, uri: &str) -> Result<()> {
+    require!(name.len() <= MAX_NAME_LEN, LaunchError::NameTooLong);
+    require!(symbol.len() <= MAX_SYMBOL_LEN, LaunchError::SymbolTooLong);
+    require!(uri.len() <= MAX_URI_LEN, LaunchError::UriTooLong);
+    Ok(())
+}
+
 #[inline(never)]
 fn create_pool_token_account_helper(ctx: &Context<Launch>) -> Result<()> {
     use anchor_spl::associated_token::Create;
@@ -137,28 +306,31 @@ fn create_metadata_helper(
 fn initialize_pool_helper(
     pool: &mut Box<Account<'_, LiquidityPool>>,
     mint_key: Pubkey,
+    quote_mint: Pubkey,
     bump: u8,
-    initial_supply: u64,
+    curve_supply: u64,
     initial_sol_reserve: u64,
     virtual_sol: u64,
 ) -> Result<()> {
     pool.token_one = mint_key;
-    // token_two is SOL (virtual), so we use the System Program ID to denote SOL.
-    pool.token_two = system_program::ID;
-    pool.total_supply = initial_supply;
-    pool.reserve_one = initial_supply;
+    // token_two is the quote mint; the System Program ID denotes native SOL
+    pool.token_two = quote_mint;
+    pool.total_supply = curve_supply;
+    pool.reserve_one = curve_supply;
     pool.reserve_two = initial_sol_reserve;
     pool.virtual_sol_reserve = virtual_sol;
     pool.bump = bump;
+    pool.pause_state = PauseState::Active;
+    pool.version = LiquidityPool::VERSION;
     
-    msg!("Pool initialized with {} real SOL + {} virtual SOL", 
+    msg!("Pool initialized with {} real + {} virtual quote reserve",
         initial_sol_reserve, virtual_sol);
     
     Ok(())
 }
 
 #[inline(never)]
-fn mint_tokens_helper(ctx: &Context<Launch>, initial_supply: u64) -> Result<()> {
+fn mint_tokens_helper(ctx: &Context<Launch>, curve_supply: u64) -> Result<()> {
     token::mint_to(
         CpiContext::new_with_signer(
             ctx.accounts.token_program.to_account_info(),
@@ -169,11 +341,93 @@ fn mint_tokens_helper(ctx: &Context<Launch>, initial_supply: u64) -> Result<()>
             },
             &[&[b"global", &[ctx.bumps.global_account]]],
         ),
-        initial_supply,
+        curve_supply,
     )?;
     Ok(())
 }
 
+/// Mint the creator allocation into the vesting escrow and record its schedule
+#[inline(never)]
+fn vesting_helper(
+    ctx: &mut Context<Launch>,
+    params: &VestingParams,
+    amount: u64,
+    start_ts: i64,
+    start_price: u64,
+) -> Result<()> {
+    let accounts = &mut ctx.accounts;
+    let (Some(vesting), Some(vesting_token_account)) = (
+        accounts.creator_vesting.as_mut(),
+        accounts.vesting_token_account.as_ref(),
+    ) else {
+        return err!(LaunchError::MissingVestingAccounts);
+    };
+    let vesting_bump = ctx.bumps.creator_vesting.ok_or(LaunchError::MissingVestingAccounts)?;
+
+    anchor_spl::associated_token::create(CpiContext::new(
+        accounts.associated_token_program.to_account_info(),
+        anchor_spl::associated_token::Create {
+            payer: accounts.creator.to_account_info(),
+            associated_token: vesting_token_account.to_account_info(),
+            authority: vesting.to_account_info(),
+            mint: accounts.mint.to_account_info(),
+            system_program: accounts.system_program.to_account_info(),
+            token_program: accounts.token_program.to_account_info(),
+        },
+    ))?;
+
+    token::mint_to(
+        CpiContext::new_with_signer(
+            accounts.token_program.to_account_info(),
+            MintTo {
+                mint: accounts.mint.to_account_info(),
+                to: vesting_token_account.to_account_info(),
+                authority: accounts.global_account.to_account_info(),
+            },
+            &[&[b"global", &[ctx.bumps.global_account]]],
+        ),
+        amount,
+    )?;
+
+    vesting.set_inner(CreatorVesting {
+        pool: accounts.pool.key(),
+        mint: accounts.mint.key(),
+        creator: accounts.creator.key(),
+        total_amount: amount,
+        claimed_amount: 0,
+        start_ts,
+        cliff_ts: start_ts + params.cliff_secs,
+        end_ts: start_ts + params.duration_secs,
+        start_price,
+        bump: vesting_bump,
+    });
+
+    msg!("Creator allocation: {} tokens vesting until {}", amount, vesting.end_ts);
+    Ok(())
+}
+
+/// Drop the mint authority once the whole supply is minted, fixing the supply for good
+#[inline(never)]
+fn revoke_mint_authority_helper(ctx: &mut Context<Launch>) -> Result<()> {
+    token::set_authority(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            SetAuthority {
+                current_authority: ctx.accounts.global_account.to_account_info(),
+                account_or_mint: ctx.accounts.mint.to_account_info(),
+            },
+            &[&[b"global", &[ctx.bumps.global_account]]],
+        ),
+        AuthorityType::MintTokens,
+        None,
+    )?;
+
+    // Refresh the mint so the event reports the authorities actually stored on-chain
+    ctx.accounts.mint.reload()?;
+    msg!("Mint authority revoked");
+    Ok(())
+}
+
 #[inline(never)]
 fn transfer_sol_helper(ctx: &Context<Launch>, initial_sol_reserve: u64) -> Result<()> {
     if initial_sol_reserve > 0 {
@@ -191,15 +445,82 @@ fn transfer_sol_helper(ctx: &Context<Launch>, initial_sol_reserve: u64) -> Resul
     Ok(())
 }
 
+/// Create the pool's quote token account (the pool PDA's associated token account) and
+/// fund it with the creator's initial reserve
+#[inline(never)]
+fn transfer_quote_reserve_helper(ctx: &Context<Launch>, initial_reserve: u64) -> Result<()> {
+    let accounts = &ctx.accounts;
+    let (Some(quote_asset), Some(quote_mint), Some(pool_quote_token_account), Some(creator_quote_token_account)) = (
+        accounts.quote_asset.as_ref(),
+        accounts.quote_mint.as_ref(),
+        accounts.pool_quote_token_account.as_ref(),
+        accounts.creator_quote_token_account.as_ref(),
+    ) else {
+        return err!(LaunchError::MissingQuoteAccounts);
+    };
+    require_keys_eq!(quote_mint.key(), quote_asset.mint, LaunchError::MissingQuoteAccounts);
+
+    anchor_spl::associated_token::create(CpiContext::new(
+        accounts.associated_token_program.to_account_info(),
+        anchor_spl::associated_token::Create {
+            payer: accounts.creator.to_account_info(),
+            associated_token: pool_quote_token_account.to_account_info(),
+            authority: accounts.pool.to_account_info(),
+            mint: quote_mint.to_account_info(),
+            system_program: accounts.system_program.to_account_info(),
+            token_program: accounts.token_program.to_account_info(),
+        },
+    ))?;
+
+    token::transfer(
+        CpiContext::new(
+            accounts.token_program.to_account_info(),
+            token::Transfer {
+                from: creator_quote_token_account.to_account_info(),
+                to: pool_quote_token_account.to_account_info(),
+                authority: accounts.creator.to_account_info(),
+            },
+        ),
+        initial_reserve,
+    )?;
+    Ok(())
+}
+
+/// Collect the launch fee into the treasury, unless the creator holds a waiver.
+/// Returns the fee actually charged.
+#[inline(never)]
+fn launch_fee_helper(ctx: &Context<Launch>) -> Result<u64> {
+    let fee = ctx.accounts.dex_configuration_account.launch_fee_lamports;
+    if fee == 0 || ctx.accounts.launch_fee_waiver.is_some() {
+        return Ok(0);
+    }
+
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.creator.to_account_info(),
+                to: ctx.accounts.treasury.to_account_info(),
+            },
+        ),
+        fee,
+    )?;
+
+    msg!("Launch fee paid: {} lamports", fee);
+    Ok(fee)
+}
+
 #[inline(never)]
 fn finalize_launch_helper(
     ctx: &Context<Launch>,
     initial_supply: u64,
+    launch_fee: u64,
     name: String,
     symbol: String,
     uri: String,
 ) -> Result<()> {
     let clock = Clock::get()?;
+    let vesting = ctx.accounts.creator_vesting.as_ref();
     
     // Emit event
     emit!(TokenLaunched {
@@ -210,12 +531,104 @@ fn finalize_launch_helper(
         symbol,
         uri,
         initial_supply,
+        decimals: ctx.accounts.mint.decimals,
+        quote_mint: ctx.accounts.pool.token_two,
+        start_price: ctx.accounts.pool.spot_price(ctx.accounts.mint.decimals)?,
+        trading_opens_at: ctx.accounts.pool.public_trading_opens_at(),
+        creator_allocation: vesting.map_or(0, |v| v.total_amount),
+        vesting_cliff_ts: vesting.map_or(0, |v| v.cliff_ts),
+        vesting_end_ts: vesting.map_or(0, |v| v.end_ts),
+        mint_authority: ctx.accounts.mint.mint_authority.into(),
+        freeze_authority: ctx.accounts.mint.freeze_authority.into(),
+        launch_fee,
         timestamp: clock.unix_timestamp,
     });
     
     Ok(())
 }
 
+/// Execute the creator's "dev buy" on the freshly initialized curve,
+/// recording their position exactly like a regular `swap` buy
+#[inline(never)]
+fn dev_buy_helper(ctx: &mut Context<Launch>, sol_in: u64) -> Result<()> {
+    let accounts = &mut ctx.accounts;
+    let (Some(position), Some(creator_token_account)) = (
+        accounts.creator_position.as_mut(),
+        accounts.creator_token_account.as_ref(),
+    ) else {
+        return err!(LaunchError::MissingDevBuyAccounts);
+    };
+    let position_bump = ctx.bumps.creator_position.ok_or(LaunchError::MissingDevBuyAccounts)?;
+
+    let pool = &mut accounts.pool;
+    let tokens_out = pool.quote_buy(sol_in, accounts.dex_configuration_account.fees)?;
+    require!(tokens_out > 0, LaunchError::InvalidDevBuy);
+
+    pool.apply_buy(tokens_out, sol_in)?;
+    msg!("Dev buy: {} lamports for {} tokens", sol_in, tokens_out);
+
+    anchor_spl::associated_token::create_idempotent(CpiContext::new(
+        accounts.associated_token_program.to_account_info(),
+        anchor_spl::associated_token::Create {
+            payer: accounts.creator.to_account_info(),
+            associated_token: creator_token_account.to_account_info(),
+            authority: accounts.creator.to_account_info(),
+            mint: accounts.mint.to_account_info(),
+            system_program: accounts.system_program.to_account_info(),
+            token_program: accounts.token_program.to_account_info(),
+        },
+    ))?;
+
+    // Transfer tokens from pool to creator
+    token::transfer(
+        CpiContext::new_with_signer(
+            accounts.token_program.to_account_info(),
+            token::Transfer {
+                from: accounts.pool_token_account.to_account_info(),
+                to: creator_token_account.to_account_info(),
+                authority: accounts.global_account.to_account_info(),
+            },
+            &[&[b"global", &[ctx.bumps.global_account]]],
+        ),
+        tokens_out,
+    )?;
+
+    // Transfer SOL from creator to global account
+    system_program::transfer(
+        CpiContext::new(
+            accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: accounts.creator.to_account_info(),
+                to: accounts.global_account.to_account_info(),
+            },
+        ),
+        sol_in,
+    )?;
+
+    position.set_inner(UserPosition::new(pool.key(), accounts.creator.key(), position_bump));
+    position.record_buy(tokens_out, sol_in)?;
+
+    emit!(TradeExecuted {
+        user: accounts.creator.key(),
+        pool: pool.key(),
+        side: "buy".to_string(),
+        token_amount: tokens_out,
+        sol_amount: sol_in,
+        referrer: None,
+        referral_fee: 0,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    emit!(PositionUpdated {
+        user: accounts.creator.key(),
+        pool: pool.key(),
+        total_tokens: position.total_tokens,
+        total_sol: position.total_sol,
+    });
+
+    Ok(())
+}
+
 #[derive(Accounts)]
 #[instruction(name: String, symbol: String, uri: String, decimals: u8)]
 pub struct Launch<'info> {
@@ -226,18 +639,33 @@ pub struct Launch<'info> {
     )]
     pub dex_configuration_account: Box<Account<'info, CurveConfiguration>>,
 
-    /// The new token mint (PDA derived from symbol for uniqueness)
+    /// The new token mint, created at a client-generated keypair (vanity addresses welcome).
+    /// Init accounts are processed in order, so the symbol is bounded here, before
+    /// `launch_record` derives its address from it
     #[account(
         init,
         payer = creator,
         mint::decimals = decimals,
         mint::authority = global_account,
-        mint::freeze_authority = global_account,
-        seeds = [b"mint", symbol.as_bytes(), creator.key().as_ref()],
-        bump,
+        constraint = symbol.len() <= MAX_SYMBOL_LEN @ LaunchError::SymbolTooLong,
     )]
     pub mint: Box<Account<'info, Mint>>,
 
+    /// Enforces the configured symbol uniqueness policy: creation fails if the
+    /// symbol was already launched in the same scope
+    #[account(
+        init,
+        payer = creator,
+        space = LaunchRecord::ACCOUNT_SIZE,
+        seeds = [
+            LaunchRecord::SEED_PREFIX.as_bytes(),
+            symbol.to_uppercase().as_bytes(),
+            dex_configuration_account.symbol_policy.scope(&creator.key(), &mint.key()).as_ref(),
+        ],
+        bump,
+    )]
+    pub launch_record: Box<Account<'info, LaunchRecord>>,
+
     /// CHECK: Metaplex Metadata account (created via CPI)
     #[account(
         mut,
@@ -274,6 +702,70 @@ pub struct Launch<'info> {
     #[account(mut)]
     pub pool_token_account: UncheckedAccount<'info>,
 
+    /// Creator's position, only needed for a dev buy
+    #[account(
+        init,
+        payer = creator,
+        space = UserPosition::ACCOUNT_SIZE,
+        seeds = [UserPosition::SEED_PREFIX.as_bytes(), pool.key().as_ref(), creator.key().as_ref()],
+        bump,
+    )]
+    pub creator_position: Option<Box<Account<'info, UserPosition>>>,
+
+    /// Creator's associated token account, only needed for a dev buy
+    /// CHECK: Created idempotently by the associated token program, which validates the address
+    #[account(mut)]
+    pub creator_token_account: Option<UncheckedAccount<'info>>,
+
+    /// Creator allocation escrow, only needed when reserving a vesting allocation
+    #[account(
+        init,
+        payer = creator,
+        space = CreatorVesting::ACCOUNT_SIZE,
+        seeds = [CreatorVesting::SEED_PREFIX.as_bytes(), pool.key().as_ref()],
+        bump,
+    )]
+    pub creator_vesting: Option<Box<Account<'info, CreatorVesting>>>,
+
+    /// Escrow's associated token account holding the allocation
+    /// CHECK: Created by the associated token program, which validates the address
+    #[account(mut)]
+    pub vesting_token_account: Option<UncheckedAccount<'info>>,
+
+    /// Treasury receiving the launch fee
+    /// CHECK: Must match the configured treasury
+    #[account(
+        mut,
+        address = dex_configuration_account.treasury,
+    )]
+    pub treasury: UncheckedAccount<'info>,
+
+    /// Creator's launch fee waiver, if the admin granted one
+    #[account(
+        seeds = [LaunchFeeWaiver::SEED_PREFIX.as_bytes(), creator.key().as_ref()],
+        bump = launch_fee_waiver.bump,
+    )]
+    pub launch_fee_waiver: Option<Box<Account<'info, LaunchFeeWaiver>>>,
+
+    /// Registered quote asset, only for a curve quoted in an SPL token instead of SOL
+    #[account(
+        seeds = [QuoteAsset::SEED_PREFIX.as_bytes(), quote_asset.mint.as_ref()],
+        bump = quote_asset.bump,
+    )]
+    pub quote_asset: Option<Box<Account<'info, QuoteAsset>>>,
+
+    /// Mint of the quote asset
+    pub quote_mint: Option<Box<Account<'info, Mint>>>,
+
+    /// Pool's quote token account (the pool PDA's associated token account)
+    /// CHECK: Created by the associated token program, which validates the address
+    #[account(mut)]
+    pub pool_quote_token_account: Option<UncheckedAccount<'info>>,
+
+    /// Creator's quote token account funding the initial reserve
+    #[account(mut)]
+    pub creator_quote_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
     /// The creator/payer
     #[account(mut)]
     pub creator: Signer<'info>,
@@ -306,8 +798,28 @@ pub enum LaunchError {
     SymbolTooLong,
     #[msg("Metadata URI is too long (max 200 characters)")]
     UriTooLong,
-    #[msg("Initial supply must be greater than 0")]
+    #[msg("Initial supply must be greater than 0 and fit in base units")]
     InvalidSupply,
+    #[msg("Decimals must be 6 or 9")]
+    InvalidDecimals,
+    #[msg("Curve starting price is outside the configured bounds")]
+    StartPriceOutOfBounds,
     #[msg("Initial SOL reserve must be greater than 0")]
     InvalidSolReserve,
+    #[msg("Dev buy must spend more than 0 SOL and receive tokens")]
+    InvalidDevBuy,
+    #[msg("Dev buy requires the creator position and token accounts")]
+    MissingDevBuyAccounts,
+    #[msg("Trading start must be in the future and at most 30 days away")]
+    InvalidTradingStart,
+    #[msg("Fair launch must end within 30 days and sell between 1 token and the initial supply")]
+    InvalidFairLaunch,
+    #[msg("A fair launch cannot be combined with a presale or dev buy")]
+    IncompatibleLaunchOptions,
+    #[msg("Creator vesting requires the vesting escrow and token accounts")]
+    MissingVestingAccounts,
+    #[msg("Presales, fair launches and dev buys require a SOL-quoted pool")]
+    NativeQuoteOnly,
+    #[msg("An SPL-quoted launch requires the quote mint and token accounts")]
+    MissingQuoteAccounts,
 }
@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::CustomError,
+    state::{CurveConfiguration, LaunchFeeWaiver},
+};
+
+/// Event emitted when the admin grants or revokes a creator's launch fee waiver
+#[event]
+pub struct LaunchFeeWaiverUpdated {
+    pub creator: Pubkey,
+    pub waived: bool,
+    pub admin: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Exempt a creator from the launch fee (admin only)
+pub fn grant_launch_fee_waiver(ctx: Context<GrantLaunchFeeWaiver>, creator: Pubkey) -> Result<()> {
+    ctx.accounts.launch_fee_waiver.set_inner(LaunchFeeWaiver {
+        creator,
+        bump: ctx.bumps.launch_fee_waiver,
+    });
+
+    msg!("Launch fee waived for creator: {:?}", creator);
+
+    emit!(LaunchFeeWaiverUpdated {
+        creator,
+        waived: true,
+        admin: ctx.accounts.admin.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Remove a creator's launch fee waiver (admin only); the rent goes back to the admin
+pub fn revoke_launch_fee_waiver(ctx: Context<RevokeLaunchFeeWaiver>) -> Result<()> {
+    let creator = ctx.accounts.launch_fee_waiver.creator;
+
+    msg!("Launch fee waiver revoked for creator: {:?}", creator);
+
+    emit!(LaunchFeeWaiverUpdated {
+        creator,
+        waived: false,
+        admin: ctx.accounts.admin.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(creator: Pubkey)]
+pub struct GrantLaunchFeeWaiver<'info> {
+    #[account(
+        seeds = [CurveConfiguration::SEED.as_bytes()],
+        bump,
+        constraint = dex_configuration_account.admin == admin.key() @ CustomError::Unauthorized,
+    )]
+    pub dex_configuration_account: Box<Account<'info, CurveConfiguration>>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = LaunchFeeWaiver::ACCOUNT_SIZE,
+        seeds = [LaunchFeeWaiver::SEED_PREFIX.as_bytes(), creator.as_ref()],
+        bump,
+    )]
+    pub launch_fee_waiver: Box<Account<'info, LaunchFeeWaiver>>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RevokeLaunchFeeWaiver<'info> {
+    #[account(
+        seeds = [CurveConfiguration::SEED.as_bytes()],
+        bump,
+        constraint = dex_configuration_account.admin == admin.key() @ CustomError::Unauthorized,
+    )]
+    pub dex_configuration_account: Box<Account<'info, CurveConfiguration>>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [LaunchFeeWaiver::SEED_PREFIX.as_bytes(), launch_fee_waiver.creator.as_ref()],
+        bump = launch_fee_waiver.bump,
+    )]
+    pub launch_fee_waiver: Box<Account<'info, LaunchFeeWaiver>>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+}
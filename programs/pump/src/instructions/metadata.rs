@@ -0,0 +1,166 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    metadata::{
+        mpl_token_metadata::types::DataV2,
+        update_metadata_accounts_v2,
+        Metadata,
+        MetadataAccount,
+        UpdateMetadataAccountsV2,
+    },
+    token::Mint,
+};
+
+use crate::{
+    errors::CustomError,
+    instructions::launch::validate_metadata,
+    state::{CurveConfiguration, LiquidityPool, SymbolPolicy},
+};
+
+/// Event emitted when the creator updates the token metadata
+#[event]
+pub struct TokenMetadataUpdated {
+    pub pool: Pubkey,
+    pub mint: Pubkey,
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub timestamp: i64,
+}
+
+/// Event emitted when the token metadata is made immutable
+#[event]
+pub struct TokenMetadataLocked {
+    pub pool: Pubkey,
+    pub mint: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Replace the token's name, symbol and URI (creator only).
+///
+/// The program stays the Metaplex update authority and signs the update, so the same
+/// length limits as `launch` apply. While symbols must be unique, the symbol itself can
+/// only change case: its `LaunchRecord` keeps reserving the launched one.
+pub fn update_token_metadata(
+    ctx: Context<UpdateTokenMetadata>,
+    name: String,
+    symbol: String,
+    uri: String,
+) -> Result<()> {
+    validate_metadata(&name, &symbol, &uri)?;
+    require!(ctx.accounts.metadata.is_mutable, CustomError::MetadataLocked);
+    if ctx.accounts.dex_configuration_account.symbol_policy != SymbolPolicy::Unrestricted {
+        let current_symbol = ctx.accounts.metadata.symbol.trim_end_matches('\0');
+        require!(
+            symbol.to_uppercase() == current_symbol.to_uppercase(),
+            CustomError::SymbolLocked
+        );
+    }
+
+    let data = DataV2 {
+        name: name.clone(),
+        symbol: symbol.clone(),
+        uri: uri.clone(),
+        seller_fee_basis_points: 0,
+        creators: None,
+        collection: None,
+        uses: None,
+    };
+
+    update_metadata_accounts_v2(
+        CpiContext::new_with_signer(
+            ctx.accounts.metadata_program.to_account_info(),
+            UpdateMetadataAccountsV2 {
+                metadata: ctx.accounts.metadata.to_account_info(),
+                update_authority: ctx.accounts.global_account.to_account_info(),
+            },
+            &[&[b"global", &[ctx.bumps.global_account]]],
+        ),
+        None,
+        Some(data),
+        None,
+        None,
+    )?;
+
+    msg!("Metadata updated: {} ({})", name, symbol);
+
+    emit!(TokenMetadataUpdated {
+        pool: ctx.accounts.pool.key(),
+        mint: ctx.accounts.mint.key(),
+        name,
+        symbol,
+        uri,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Make the token metadata immutable for good (creator only)
+pub fn lock_metadata(ctx: Context<UpdateTokenMetadata>) -> Result<()> {
+    require!(ctx.accounts.metadata.is_mutable, CustomError::MetadataLocked);
+
+    update_metadata_accounts_v2(
+        CpiContext::new_with_signer(
+            ctx.accounts.metadata_program.to_account_info(),
+            UpdateMetadataAccountsV2 {
+                metadata: ctx.accounts.metadata.to_account_info(),
+                update_authority: ctx.accounts.global_account.to_account_info(),
+            },
+            &[&[b"global", &[ctx.bumps.global_account]]],
+        ),
+        None,
+        None,
+        None,
+        Some(false),
+    )?;
+
+    msg!("Metadata locked for mint: {:?}", ctx.accounts.mint.key());
+
+    emit!(TokenMetadataLocked {
+        pool: ctx.accounts.pool.key(),
+        mint: ctx.accounts.mint.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateTokenMetadata<'info> {
+    #[account(
+        seeds = [CurveConfiguration::SEED.as_bytes()],
+        bump,
+    )]
+    pub dex_configuration_account: Box<Account<'info, CurveConfiguration>>,
+
+    #[account(
+        seeds = [LiquidityPool::POOL_SEED_PREFIX.as_bytes(), mint.key().as_ref()],
+        bump = pool.bump,
+        constraint = pool.is_creator(&creator.key()) @ CustomError::NotPoolCreator,
+    )]
+    pub pool: Box<Account<'info, LiquidityPool>>,
+
+    pub mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"metadata",
+            metadata_program.key().as_ref(),
+            mint.key().as_ref(),
+        ],
+        bump,
+        seeds::program = metadata_program.key(),
+    )]
+    pub metadata: Box<Account<'info, MetadataAccount>>,
+
+    /// CHECK: Global PDA, the metadata update authority
+    #[account(
+        seeds = [b"global"],
+        bump,
+    )]
+    pub global_account: AccountInfo<'info>,
+
+    pub creator: Signer<'info>,
+    pub metadata_program: Program<'info, Metadata>,
+}
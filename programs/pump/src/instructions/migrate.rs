@@ -0,0 +1,202 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::{
+    errors::CustomError,
+    state::{CurveConfiguration, LiquidityPool, UserPosition},
+};
+
+/// Event emitted when an account is upgraded to the current layout
+#[event]
+pub struct AccountMigrated {
+    pub account: Pubkey,
+    pub from_version: u8,
+    pub to_version: u8,
+}
+
+/// Upgrade the configuration account to the current layout (admin only).
+///
+/// Legacy accounts are reallocated to `CurveConfiguration::ACCOUNT_SIZE` (the admin
+/// pays for the extra rent) and new fields are filled with their defaults.
+pub fn migrate_config(ctx: Context<MigrateConfig>) -> Result<()> {
+    let account = ctx.accounts.dex_configuration_account.to_account_info();
+
+    grow_account(
+        &account,
+        &ctx.accounts.admin,
+        &ctx.accounts.system_program,
+        CurveConfiguration::ACCOUNT_SIZE,
+    )?;
+
+    let mut config = load_account::<CurveConfiguration>(&account)?;
+    require!(ctx.accounts.admin.key() == config.admin, CustomError::Unauthorized);
+    require!(config.version <= CurveConfiguration::VERSION, CustomError::UnsupportedAccountVersion);
+
+    let from_version = config.version;
+    config.migrate();
+    store_account(&account, &config)?;
+
+    emit_migrated(account.key(), from_version, config.version);
+    Ok(())
+}
+
+/// Upgrade a pool account to the current layout. Permissionless: the payer covers the extra rent.
+pub fn migrate_pool(ctx: Context<MigratePool>) -> Result<()> {
+    let account = ctx.accounts.pool.to_account_info();
+
+    grow_account(
+        &account,
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+        LiquidityPool::ACCOUNT_SIZE,
+    )?;
+
+    let mut pool = load_account::<LiquidityPool>(&account)?;
+    require!(pool.version <= LiquidityPool::VERSION, CustomError::UnsupportedAccountVersion);
+
+    let from_version = pool.version;
+    pool.migrate();
+    store_account(&account, &pool)?;
+
+    emit_migrated(account.key(), from_version, pool.version);
+    Ok(())
+}
+
+/// Upgrade a user position to the current layout. Permissionless: the payer covers the extra rent.
+pub fn migrate_position(ctx: Context<MigratePosition>) -> Result<()> {
+    let account = ctx.accounts.user_position.to_account_info();
+
+    grow_account(
+        &account,
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+        UserPosition::ACCOUNT_SIZE,
+    )?;
+
+    let mut position = load_account::<UserPosition>(&account)?;
+    require!(position.version <= UserPosition::VERSION, CustomError::UnsupportedAccountVersion);
+
+    let from_version = position.version;
+    position.migrate();
+    store_account(&account, &position)?;
+
+    emit_migrated(account.key(), from_version, position.version);
+    Ok(())
+}
+
+/// Reallocate a program-owned account to `new_size`, topping up rent from `payer`.
+/// Existing data is preserved and the new space is zero-filled.
+fn grow_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+    new_size: usize,
+) -> Result<()> {
+    require_keys_eq!(*account.owner, crate::ID, ErrorCode::AccountOwnedByWrongProgram);
+
+    if account.data_len() >= new_size {
+        return Ok(());
+    }
+
+    let required_lamports = Rent::get()?.minimum_balance(new_size);
+    let shortfall = required_lamports.saturating_sub(account.lamports());
+    if shortfall > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                system_program::Transfer {
+                    from: payer.to_account_info(),
+                    to: account.clone(),
+                },
+            ),
+            shortfall,
+        )?;
+    }
+
+    account.resize(new_size)?;
+    msg!("Account {} reallocated to {} bytes", account.key(), new_size);
+    Ok(())
+}
+
+/// Deserialize an account, checking its discriminator
+fn load_account<T: AccountDeserialize>(account: &AccountInfo) -> Result<T> {
+    let data = account.try_borrow_data()?;
+    T::try_deserialize(&mut &data[..])
+}
+
+/// Serialize an account back into its data, including the discriminator
+fn store_account<T: AccountSerialize>(account: &AccountInfo, value: &T) -> Result<()> {
+    let mut data = account.try_borrow_mut_data()?;
+    value.try_serialize(&mut &mut data[..])
+}
+
+fn emit_migrated(account: Pubkey, from_version: u8, to_version: u8) {
+    if from_version == to_version {
+        msg!("Account {} already at version {}", account, to_version);
+        return;
+    }
+
+    msg!("Account {} migrated from version {} to {}", account, from_version, to_version);
+    emit!(AccountMigrated {
+        account,
+        from_version,
+        to_version,
+    });
+}
+
+#[derive(Accounts)]
+pub struct MigrateConfig<'info> {
+    /// CHECK: Legacy layouts cannot be deserialized before they are resized;
+    /// owner and discriminator are checked in the handler
+    #[account(
+        mut,
+        seeds = [CurveConfiguration::SEED.as_bytes()],
+        bump,
+    )]
+    pub dex_configuration_account: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MigratePool<'info> {
+    /// CHECK: Legacy layouts cannot be deserialized before they are resized;
+    /// owner and discriminator are checked in the handler
+    #[account(
+        mut,
+        seeds = [LiquidityPool::POOL_SEED_PREFIX.as_bytes(), mint.key().as_ref()],
+        bump,
+    )]
+    pub pool: UncheckedAccount<'info>,
+
+    /// CHECK: Only used to derive the pool address
+    pub mint: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MigratePosition<'info> {
+    /// CHECK: Legacy layouts cannot be deserialized before they are resized;
+    /// owner and discriminator are checked in the handler
+    #[account(
+        mut,
+        seeds = [UserPosition::SEED_PREFIX.as_bytes(), pool.key().as_ref(), owner.key().as_ref()],
+        bump,
+    )]
+    pub user_position: UncheckedAccount<'info>,
+
+    /// CHECK: Only used to derive the position address
+    pub pool: UncheckedAccount<'info>,
+
+    /// CHECK: Only used to derive the position address
+    pub owner: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
@@ -1,15 +1,39 @@
 pub mod add_liquidity;
+pub mod close_position;
+pub mod fair_launch;
 pub mod initialize;
 pub mod launch;
+pub mod launch_fee;
+pub mod metadata;
+pub mod migrate;
+pub mod pause;
+pub mod presale;
+pub mod quote;
+pub mod quote_asset;
+pub mod referral;
 pub mod remove_liquidity;
 pub mod swap;
+pub mod swap_token_to_token;
+pub mod vesting;
 // pub mod create_raydium_pool;
 
 pub use add_liquidity::*;
+pub use close_position::*;
+pub use fair_launch::*;
 pub use initialize::*;
 pub use launch::*;
+pub use launch_fee::*;
+pub use metadata::*;
+pub use migrate::*;
+pub use pause::*;
+pub use presale::*;
+pub use quote::*;
+pub use quote_asset::*;
+pub use referral::*;
 pub use remove_liquidity::*;
 pub use swap::*;
+pub use swap_token_to_token::*;
+pub use vesting::*;
 pub mod update_config;
 pub use update_config::*;
 // pub use create_raydium_pool::*;
@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::CustomError,
+    state::{CurveConfiguration, LiquidityPool, PauseState},
+};
+
+/// Event emitted whenever the global or a pool's pause state changes
+#[event]
+pub struct PauseStateChanged {
+    /// `None` for the global switch, otherwise the pool that was (un)paused
+    pub pool: Option<Pubkey>,
+    pub pause_state: PauseState,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Pause trading globally, or for a single pool when `pool` is provided.
+///
+/// With `allow_sells` the target enters a wind-down where sells keep working
+/// but buys, launches and liquidity adds are rejected.
+pub fn pause(ctx: Context<SetPauseState>, allow_sells: bool) -> Result<()> {
+    let pause_state = if allow_sells {
+        PauseState::SellOnly
+    } else {
+        PauseState::Paused
+    };
+
+    set_pause_state(ctx, pause_state)
+}
+
+/// Resume normal trading globally, or for a single pool when `pool` is provided
+pub fn unpause(ctx: Context<SetPauseState>) -> Result<()> {
+    set_pause_state(ctx, PauseState::Active)
+}
+
+fn set_pause_state(ctx: Context<SetPauseState>, pause_state: PauseState) -> Result<()> {
+    let authority = ctx.accounts.authority.key();
+    let dex_config = &mut ctx.accounts.dex_configuration_account;
+
+    require!(
+        authority == dex_config.pauser || authority == dex_config.admin,
+        CustomError::UnauthorizedPauser
+    );
+
+    let pool = match ctx.accounts.pool.as_mut() {
+        Some(pool) => {
+            pool.pause_state = pause_state;
+            msg!("Pool {} pause state set to {:?}", pool.key(), pause_state);
+            Some(pool.key())
+        }
+        None => {
+            dex_config.pause_state = pause_state;
+            msg!("Global pause state set to {:?}", pause_state);
+            None
+        }
+    };
+
+    emit!(PauseStateChanged {
+        pool,
+        pause_state,
+        authority,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetPauseState<'info> {
+    #[account(
+        mut,
+        seeds = [CurveConfiguration::SEED.as_bytes()],
+        bump,
+    )]
+    pub dex_configuration_account: Box<Account<'info, CurveConfiguration>>,
+
+    /// Pool to (un)pause; omit to change the global state
+    #[account(
+        mut,
+        seeds = [LiquidityPool::POOL_SEED_PREFIX.as_bytes(), pool.token_one.as_ref()],
+        bump = pool.bump
+    )]
+    pub pool: Option<Box<Account<'info, LiquidityPool>>>,
+
+    pub authority: Signer<'info>,
+}
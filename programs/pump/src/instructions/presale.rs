@@ -0,0 +1,177 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{self, Mint, Token, TokenAccount},
+};
+
+use crate::{
+    errors::CustomError,
+    instructions::swap::{PositionUpdated, TradeExecuted},
+    state::{CurveConfiguration, LiquidityPool, UserPosition},
+    utils::merkle::{presale_leaf, verify_proof},
+};
+
+/// Buy during a pool's allowlisted presale.
+///
+/// The caller proves membership with a Merkle proof of the leaf
+/// keccak256(user || allocation), and can spend at most `allocation` lamports
+/// across all presale buys. Buys are recorded in a regular `UserPosition`, so the
+/// PaperHand tax applies to presale tokens like any other.
+pub fn presale_buy(
+    ctx: Context<PresaleBuy>,
+    amount: u64,
+    min_amount_out: u64,
+    allocation: u64,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    if amount == 0 {
+        return err!(CustomError::InvalidAmount);
+    }
+
+    let pool = &mut ctx.accounts.pool;
+    let config = &ctx.accounts.dex_configuration_account;
+    let position = &mut ctx.accounts.user_position;
+    let clock = Clock::get()?;
+
+    config.pause_state.ensure_buys_allowed()?;
+    pool.pause_state.ensure_buys_allowed()?;
+
+    require!(pool.presale.is_active(clock.unix_timestamp), CustomError::PresaleNotActive);
+
+    let leaf = presale_leaf(&ctx.accounts.user.key(), allocation);
+    require!(
+        verify_proof(&proof, &pool.presale.merkle_root, leaf),
+        CustomError::InvalidPresaleProof
+    );
+
+    // Freshly created position (init_if_needed): record its identity and layout version
+    if position.owner == Pubkey::default() {
+        position.set_inner(UserPosition::new(
+            pool.key(),
+            ctx.accounts.user.key(),
+            ctx.bumps.user_position,
+        ));
+    }
+
+    let presale_sol = position.presale_sol
+        .checked_add(amount)
+        .ok_or(CustomError::MathOverflow)?;
+    require!(presale_sol <= allocation, CustomError::PresaleAllocationExceeded);
+    position.presale_sol = presale_sol;
+
+    let tokens_out = pool.quote_buy(amount, config.fees)?;
+
+    // Slippage Check
+    if tokens_out < min_amount_out {
+        return err!(CustomError::SlippageExceeded);
+    }
+
+    pool.apply_buy(tokens_out, amount)?;
+
+    msg!("Presale buy: {:?} {:?} -> {:?}", ctx.accounts.user.key(), amount, tokens_out);
+
+    // Transfer tokens from pool to user
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token::Transfer {
+                from: ctx.accounts.pool_token_account_one.to_account_info(),
+                to: ctx.accounts.user_token_account_one.to_account_info(),
+                authority: ctx.accounts.global_account.to_account_info(),
+            },
+            &[&[b"global", &[ctx.bumps.global_account]]],
+        ),
+        tokens_out,
+    )?;
+
+    // Transfer SOL from user to global account
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.user.to_account_info(),
+                to: ctx.accounts.global_account.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    position.record_buy(tokens_out, amount)?;
+
+    emit!(TradeExecuted {
+        user: ctx.accounts.user.key(),
+        pool: pool.key(),
+        side: "buy".to_string(),
+        token_amount: tokens_out,
+        sol_amount: amount,
+        referrer: None,
+        referral_fee: 0,
+        timestamp: clock.unix_timestamp,
+    });
+
+    emit!(PositionUpdated {
+        user: ctx.accounts.user.key(),
+        pool: pool.key(),
+        total_tokens: position.total_tokens,
+        total_sol: position.total_sol,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct PresaleBuy<'info> {
+    #[account(
+        seeds = [CurveConfiguration::SEED.as_bytes()],
+        bump,
+    )]
+    pub dex_configuration_account: Box<Account<'info, CurveConfiguration>>,
+
+    #[account(
+        mut,
+        seeds = [LiquidityPool::POOL_SEED_PREFIX.as_bytes(), mint_token_one.key().as_ref()],
+        bump = pool.bump
+    )]
+    pub pool: Box<Account<'info, LiquidityPool>>,
+
+    /// CHECK: Global SOL vault PDA
+    #[account(
+        mut,
+        seeds = [b"global"],
+        bump,
+    )]
+    pub global_account: AccountInfo<'info>,
+
+    /// User position account for tracking cost basis (init_if_needed on first buy)
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = UserPosition::ACCOUNT_SIZE,
+        seeds = [UserPosition::SEED_PREFIX.as_bytes(), pool.key().as_ref(), user.key().as_ref()],
+        bump,
+    )]
+    pub user_position: Box<Account<'info, UserPosition>>,
+
+    pub mint_token_one: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint_token_one,
+        associated_token::authority = global_account
+    )]
+    pub pool_token_account_one: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint_token_one,
+        associated_token::authority = user,
+    )]
+    pub user_token_account_one: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
@@ -0,0 +1,226 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+use crate::{
+    errors::CustomError,
+    state::{calculate_paperhand_tax, CurveConfiguration, LiquidityPool, PauseState, UserPosition},
+};
+
+/// Preview of a buy, as `swap` would execute it now
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct BuyQuote {
+    pub sol_in: u64,
+    pub tokens_out: u64,
+    /// Trading fee included in `sol_in`
+    pub trading_fee: u64,
+    pub price_impact_bps: u64,
+}
+
+/// Preview of a sell, as `swap` would execute it now for the seller
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct SellQuote {
+    pub tokens_in: u64,
+    /// Tokens that would actually be sold: less than `tokens_in` when the real SOL
+    /// reserve cannot pay for all of them (only executable with `allow_partial_fill`)
+    pub tokens_filled: u64,
+    pub sol_out_before_tax: u64,
+    /// Seller's cost basis for `tokens_filled` (0 without a position)
+    pub cost_basis: u64,
+    /// Expected PaperHand tax, using the seller's position and tax rate
+    pub paperhand_tax: u64,
+    pub sol_to_user: u64,
+    /// Trading fee, valued in lamports of proceeds forgone
+    pub trading_fee: u64,
+    pub price_impact_bps: u64,
+}
+
+/// Snapshot of a pool and the configuration values that drive its pricing
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct PoolState {
+    pub mint: Pubkey,
+    pub creator: Pubkey,
+    /// Quote mint, or the System Program ID for native SOL
+    pub quote_mint: Pubkey,
+    pub reserve_one: u64,
+    pub reserve_two: u64,
+    pub virtual_sol_reserve: u64,
+    pub effective_sol_reserve: u64,
+    /// Spot price in lamports per whole token, scaled by `PRICE_SCALE`
+    pub spot_price: u64,
+    /// Largest sell the real SOL reserve can currently pay for
+    pub max_sell_tokens: u64,
+    pub fees_bps: u16,
+    pub paperhand_tax_bps: u16,
+    pub global_pause_state: PauseState,
+    pub pool_pause_state: PauseState,
+    pub trading_opens_at: i64,
+    pub fair_launch_settled: bool,
+}
+
+/// Quote a buy of `sol_in` lamports by `buyer`. Fails like `swap` would while public
+/// trading is closed, buys are paused, or the buy breaks the launch protection limits.
+/// Read-only; the result is returned via return data.
+pub fn quote_buy(ctx: Context<QuotePool>, sol_in: u64) -> Result<BuyQuote> {
+    require!(sol_in > 0, CustomError::InvalidAmount);
+
+    let pool = &ctx.accounts.pool;
+    let config = &ctx.accounts.dex_configuration_account;
+    let fees = config.fees;
+    let now = Clock::get()?.unix_timestamp;
+
+    pool.ensure_public_trading(now)?;
+    config.pause_state.ensure_buys_allowed()?;
+    pool.pause_state.ensure_buys_allowed()?;
+
+    let tokens_out = pool.quote_buy(sol_in, fees)?;
+    let price_impact_bps = pool.buy_price_impact_bps(tokens_out, sol_in)?;
+
+    // Anti-sniper limits during the launch window, checked on a copy of the position
+    if pool.launch_protection.is_active(now) {
+        let mut position = match ctx.accounts.user_position.as_ref() {
+            Some(position) => UserPosition::clone(position),
+            None => UserPosition::new(pool.key(), ctx.accounts.buyer.key(), 0),
+        };
+        pool.launch_protection.enforce_buy(&mut position, sol_in, tokens_out, price_impact_bps)?;
+    }
+
+    Ok(BuyQuote {
+        sol_in,
+        tokens_out,
+        trading_fee: LiquidityPool::buy_fee(sol_in, fees)?,
+        price_impact_bps,
+    })
+}
+
+/// Quote a sell of `tokens_in` by `seller`, including the PaperHand tax their position
+/// would incur. Fails like `swap` would while public trading is closed, sells are
+/// paused, or the creator's sells are locked. Read-only; the result is returned via return data.
+pub fn quote_sell(ctx: Context<QuoteSell>, tokens_in: u64) -> Result<SellQuote> {
+    require!(tokens_in > 0, CustomError::InvalidAmount);
+
+    let pool = &ctx.accounts.pool;
+    let config = &ctx.accounts.dex_configuration_account;
+    let now = Clock::get()?.unix_timestamp;
+
+    pool.ensure_public_trading(now)?;
+    config.pause_state.ensure_sells_allowed()?;
+    pool.pause_state.ensure_sells_allowed()?;
+    if pool.is_creator(&ctx.accounts.seller.key()) {
+        pool.creator_rules.ensure_sells_unlocked(now)?;
+    }
+
+    let tokens_filled = tokens_in.min(pool.max_sell_for_real_sol(config.fees)?);
+    let sol_out_before_tax = pool.quote_sell(tokens_filled, config.fees)?;
+
+    let cost_basis = match ctx.accounts.user_position.as_ref() {
+        Some(position) => {
+            require!(position.total_tokens >= tokens_filled, CustomError::InsufficientPosition);
+            position.calculate_cost_basis_for_sale(tokens_filled)?
+        }
+        None => 0,
+    };
+    let tax_bps = pool.paperhand_tax_bps_for(&ctx.accounts.seller.key(), config.paperhand_tax_bps);
+    let paperhand_tax = calculate_paperhand_tax(sol_out_before_tax, cost_basis, tax_bps)?;
+
+    Ok(SellQuote {
+        tokens_in,
+        tokens_filled,
+        sol_out_before_tax,
+        cost_basis,
+        paperhand_tax,
+        sol_to_user: sol_out_before_tax - paperhand_tax,
+        trading_fee: pool.sell_fee(tokens_filled, config.fees)?,
+        price_impact_bps: pool.sell_price_impact_bps(tokens_filled, sol_out_before_tax)?,
+    })
+}
+
+/// Current state of a pool. Read-only; the result is returned via return data.
+pub fn get_pool_state(ctx: Context<GetPoolState>) -> Result<PoolState> {
+    let pool = &ctx.accounts.pool;
+    let config = &ctx.accounts.dex_configuration_account;
+
+    Ok(PoolState {
+        mint: pool.token_one,
+        creator: pool.creator,
+        quote_mint: pool.quote_mint().unwrap_or(anchor_lang::system_program::ID),
+        reserve_one: pool.reserve_one,
+        reserve_two: pool.reserve_two,
+        virtual_sol_reserve: pool.virtual_sol_reserve,
+        effective_sol_reserve: pool.effective_sol_reserve(),
+        spot_price: pool.spot_price(ctx.accounts.mint.decimals)?,
+        max_sell_tokens: pool.max_sell_for_real_sol(config.fees)?,
+        fees_bps: config.fees,
+        paperhand_tax_bps: config.paperhand_tax_bps,
+        global_pause_state: config.pause_state,
+        pool_pause_state: pool.pause_state,
+        trading_opens_at: pool.public_trading_opens_at(),
+        fair_launch_settled: !pool.fair_launch.blocks_trading(),
+    })
+}
+
+#[derive(Accounts)]
+pub struct QuotePool<'info> {
+    #[account(
+        seeds = [CurveConfiguration::SEED.as_bytes()],
+        bump,
+    )]
+    pub dex_configuration_account: Box<Account<'info, CurveConfiguration>>,
+
+    #[account(
+        seeds = [LiquidityPool::POOL_SEED_PREFIX.as_bytes(), pool.token_one.as_ref()],
+        bump = pool.bump
+    )]
+    pub pool: Box<Account<'info, LiquidityPool>>,
+
+    /// CHECK: Wallet whose buy is quoted; only its address is used
+    pub buyer: UncheckedAccount<'info>,
+
+    /// Buyer's position, for what they already bought in the launch protection window
+    #[account(
+        seeds = [UserPosition::SEED_PREFIX.as_bytes(), pool.key().as_ref(), buyer.key().as_ref()],
+        bump,
+    )]
+    pub user_position: Option<Box<Account<'info, UserPosition>>>,
+}
+
+#[derive(Accounts)]
+pub struct QuoteSell<'info> {
+    #[account(
+        seeds = [CurveConfiguration::SEED.as_bytes()],
+        bump,
+    )]
+    pub dex_configuration_account: Box<Account<'info, CurveConfiguration>>,
+
+    #[account(
+        seeds = [LiquidityPool::POOL_SEED_PREFIX.as_bytes(), pool.token_one.as_ref()],
+        bump = pool.bump
+    )]
+    pub pool: Box<Account<'info, LiquidityPool>>,
+
+    /// CHECK: Wallet whose sell is quoted; only its address is used
+    pub seller: UncheckedAccount<'info>,
+
+    /// Seller's position, for the cost basis behind the PaperHand tax
+    #[account(
+        seeds = [UserPosition::SEED_PREFIX.as_bytes(), pool.key().as_ref(), seller.key().as_ref()],
+        bump,
+    )]
+    pub user_position: Option<Box<Account<'info, UserPosition>>>,
+}
+
+#[derive(Accounts)]
+pub struct GetPoolState<'info> {
+    #[account(
+        seeds = [CurveConfiguration::SEED.as_bytes()],
+        bump,
+    )]
+    pub dex_configuration_account: Box<Account<'info, CurveConfiguration>>,
+
+    #[account(
+        seeds = [LiquidityPool::POOL_SEED_PREFIX.as_bytes(), mint.key().as_ref()],
+        bump = pool.bump
+    )]
+    pub pool: Box<Account<'info, LiquidityPool>>,
+
+    pub mint: Box<Account<'info, Mint>>,
+}
@@ -0,0 +1,119 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+use crate::{
+    errors::CustomError,
+    state::{CurveConfiguration, QuoteAsset},
+};
+
+/// Event emitted when the admin allows or removes an SPL quote asset
+#[event]
+pub struct QuoteAssetUpdated {
+    pub mint: Pubkey,
+    pub enabled: bool,
+    pub default_virtual_reserve: u64,
+    pub min_start_price: u64,
+    pub max_start_price: u64,
+    pub admin: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Allow new pools to be quoted in `quote_mint` (admin only)
+pub fn register_quote_asset(
+    ctx: Context<RegisterQuoteAsset>,
+    default_virtual_reserve: u64,
+    min_start_price: u64,
+    max_start_price: u64,
+) -> Result<()> {
+    require!(default_virtual_reserve > 0, CustomError::InvalidVirtualSol);
+    require!(min_start_price <= max_start_price, CustomError::InvalidPriceBounds);
+
+    let mint = ctx.accounts.quote_mint.key();
+    ctx.accounts.quote_asset.set_inner(QuoteAsset {
+        mint,
+        default_virtual_reserve,
+        min_start_price,
+        max_start_price,
+        bump: ctx.bumps.quote_asset,
+    });
+
+    msg!("Quote asset registered: {:?}", mint);
+
+    emit!(QuoteAssetUpdated {
+        mint,
+        enabled: true,
+        default_virtual_reserve,
+        min_start_price,
+        max_start_price,
+        admin: ctx.accounts.admin.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Stop new launches in a quote asset (admin only). Existing pools keep trading; the
+/// rent goes back to the admin.
+pub fn unregister_quote_asset(ctx: Context<UnregisterQuoteAsset>) -> Result<()> {
+    let quote_asset = &ctx.accounts.quote_asset;
+
+    msg!("Quote asset unregistered: {:?}", quote_asset.mint);
+
+    emit!(QuoteAssetUpdated {
+        mint: quote_asset.mint,
+        enabled: false,
+        default_virtual_reserve: quote_asset.default_virtual_reserve,
+        min_start_price: quote_asset.min_start_price,
+        max_start_price: quote_asset.max_start_price,
+        admin: ctx.accounts.admin.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RegisterQuoteAsset<'info> {
+    #[account(
+        seeds = [CurveConfiguration::SEED.as_bytes()],
+        bump,
+        constraint = dex_configuration_account.admin == admin.key() @ CustomError::Unauthorized,
+    )]
+    pub dex_configuration_account: Box<Account<'info, CurveConfiguration>>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = QuoteAsset::ACCOUNT_SIZE,
+        seeds = [QuoteAsset::SEED_PREFIX.as_bytes(), quote_mint.key().as_ref()],
+        bump,
+    )]
+    pub quote_asset: Box<Account<'info, QuoteAsset>>,
+
+    pub quote_mint: Box<Account<'info, Mint>>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UnregisterQuoteAsset<'info> {
+    #[account(
+        seeds = [CurveConfiguration::SEED.as_bytes()],
+        bump,
+        constraint = dex_configuration_account.admin == admin.key() @ CustomError::Unauthorized,
+    )]
+    pub dex_configuration_account: Box<Account<'info, CurveConfiguration>>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [QuoteAsset::SEED_PREFIX.as_bytes(), quote_asset.mint.as_ref()],
+        bump = quote_asset.bump,
+    )]
+    pub quote_asset: Box<Account<'info, QuoteAsset>>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+}
@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::CustomError,
+    state::Referrer,
+};
+
+/// Event emitted when a referrer withdraws their earnings
+#[event]
+pub struct ReferralClaimed {
+    pub referrer: Pubkey,
+    pub amount: u64,
+    pub total_claimed: u64,
+    pub timestamp: i64,
+}
+
+/// Create the caller's `Referrer` account so front-ends can route swaps through it
+pub fn register_referrer(ctx: Context<RegisterReferrer>) -> Result<()> {
+    ctx.accounts.referrer.set_inner(Referrer {
+        owner: ctx.accounts.owner.key(),
+        total_earned: 0,
+        total_claimed: 0,
+        bump: ctx.bumps.referrer,
+    });
+
+    msg!("Referrer registered: {:?}", ctx.accounts.owner.key());
+    Ok(())
+}
+
+/// Withdraw all unclaimed referral fees to the referrer's wallet
+pub fn claim_referral(ctx: Context<ClaimReferral>) -> Result<()> {
+    let referrer = &mut ctx.accounts.referrer;
+
+    let amount = referrer.unclaimed();
+    require!(amount > 0, CustomError::NothingToClaimReferral);
+
+    referrer.total_claimed = referrer.total_claimed
+        .checked_add(amount)
+        .ok_or(CustomError::MathOverflow)?;
+
+    // Earnings sit on top of the rent-exempt balance of this program-owned account
+    referrer.sub_lamports(amount)?;
+    ctx.accounts.owner.add_lamports(amount)?;
+
+    msg!("Referral fees claimed: {:?} {:?}", ctx.accounts.owner.key(), amount);
+
+    emit!(ReferralClaimed {
+        referrer: ctx.accounts.owner.key(),
+        amount,
+        total_claimed: referrer.total_claimed,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RegisterReferrer<'info> {
+    #[account(
+        init,
+        payer = owner,
+        space = Referrer::ACCOUNT_SIZE,
+        seeds = [Referrer::SEED_PREFIX.as_bytes(), owner.key().as_ref()],
+        bump,
+    )]
+    pub referrer: Box<Account<'info, Referrer>>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimReferral<'info> {
+    #[account(
+        mut,
+        seeds = [Referrer::SEED_PREFIX.as_bytes(), owner.key().as_ref()],
+        bump = referrer.bump,
+        has_one = owner,
+    )]
+    pub referrer: Box<Account<'info, Referrer>>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+}
@@ -1,19 +1,29 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{Mint, Token};
 
-use crate::state::LiquidityPool;
+use crate::state::{CurveConfiguration, LiquidityPool};
 
 pub fn remove_liquidity(
-    _ctx: Context<RemoveLiquidity>,
+    ctx: Context<RemoveLiquidity>,
     _nonce: u8,
     _init_pc_amount: u64,
 ) -> Result<()> {
+    // Withdrawals follow the same rules as sells
+    ctx.accounts.dex_configuration_account.pause_state.ensure_sells_allowed()?;
+    ctx.accounts.pool.pause_state.ensure_sells_allowed()?;
+
     // If you want to Interact with CPI, then plz contact to me.
     Ok(())
 }
 
 #[derive(Accounts)]
 pub struct RemoveLiquidity<'info> {
+    #[account(
+        seeds = [CurveConfiguration::SEED.as_bytes()],
+        bump,
+    )]
+    pub dex_configuration_account: Box<Account<'info, CurveConfiguration>>,
+
     #[account(
         mut,
         seeds = [LiquidityPool::POOL_SEED_PREFIX.as_bytes(), coin_mint.key().as_ref()],
@@ -1,12 +1,18 @@
 use anchor_lang::prelude::*;
 use anchor_lang::system_program;
 use anchor_spl::{
-    associated_token::AssociatedToken,
-    token::{self, Mint, Token, TokenAccount},
+    associated_token::get_associated_token_address,
+    token::{self, spl_token::native_mint, Mint, Token, TokenAccount},
 };
 use crate::{
+    consts::WSOL_TEMP_SEED_PREFIX,
     errors::CustomError,
-    state::{CurveConfiguration, LiquidityPool, UserPosition},
+    instructions::close_position::PositionClosed,
+    state::{
+        calculate_paperhand_tax, calculate_referral_fee, pro_rata_min_out, CurveConfiguration,
+        LiquidityPool, Referrer, UserPosition,
+    },
+    utils::wsol::{sync_wsol, unwrap_wsol, UnwrapWsol},
 };
 
 /// Events for tracking trades and tax application
@@ -16,10 +22,25 @@ pub struct TradeExecuted {
     pub pool: Pubkey,
     pub side: String,       // "buy" or "sell"
     pub token_amount: u64,
+    /// In the pool's quote asset: lamports, or base units of its SPL quote mint
     pub sol_amount: u64,
+    /// Referrer credited with part of the trading fee, if any
+    pub referrer: Option<Pubkey>,
+    pub referral_fee: u64,
     pub timestamp: i64,
 }
 
+/// Amounts filled by a `swap`, returned via return data for CPI callers
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct SwapResult {
+    /// Tokens sold (sell; may be less than requested on a partial fill) or SOL spent (buy)
+    pub amount_in: u64,
+    /// SOL received after tax (sell) or tokens received (buy)
+    pub amount_out: u64,
+    pub paperhand_tax: u64,
+    pub referral_fee: u64,
+}
+
 #[event]
 pub struct PaperhandTaxApplied {
     pub user: Pubkey,
@@ -38,50 +59,113 @@ pub struct PositionUpdated {
     pub total_sol: u64,
 }
 
-pub fn swap(ctx: Context<Swap>, amount: u64, style: u64, min_amount_out: u64) -> Result<()> {
-    if amount <= 0 {
+/// Reject a transaction landing after the trader's deadline or slot
+pub(crate) fn ensure_not_expired(clock: &Clock, deadline_unix_ts: Option<i64>, max_slot: Option<u64>) -> Result<()> {
+    if let Some(deadline) = deadline_unix_ts {
+        require!(clock.unix_timestamp <= deadline, CustomError::DeadlineExceeded);
+    }
+    if let Some(max_slot) = max_slot {
+        require!(clock.slot <= max_slot, CustomError::MaxSlotExceeded);
+    }
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn swap(
+    ctx: Context<Swap>,
+    amount: u64,
+    style: u64,
+    min_amount_out: u64,
+    close_empty_position: bool,
+    deadline_unix_ts: Option<i64>,
+    max_slot: Option<u64>,
+    max_price_impact_bps: Option<u16>,
+    allow_partial_fill: bool,
+) -> Result<SwapResult> {
+    if amount == 0 {
         return err!(CustomError::InvalidAmount);
     }
 
     let pool = &mut ctx.accounts.pool;
     let config = &ctx.accounts.dex_configuration_account;
     let position = &mut ctx.accounts.user_position;
+
+    // Freshly created position (init_if_needed): record its identity and layout version
+    if position.owner == Pubkey::default() {
+        position.set_inner(UserPosition::new(
+            pool.key(),
+            ctx.accounts.user.key(),
+            ctx.bumps.user_position,
+        ));
+    }
     
     let clock = Clock::get()?;
 
+    // Reject stale transactions
+    ensure_not_expired(&clock, deadline_unix_ts, max_slot)?;
+
+    if let Some(referrer) = ctx.accounts.referrer.as_ref() {
+        require_keys_neq!(referrer.owner, ctx.accounts.user.key(), CustomError::SelfReferral);
+    }
+
+    // Pools quoted in an SPL token settle through token accounts; referral fees are
+    // paid in lamports, so they stay a native-pool feature
+    let quote_accounts = match pool.quote_mint() {
+        Some(_) => {
+            require!(ctx.accounts.referrer.is_none(), CustomError::NativeQuoteOnly);
+            let (Some(pool_quote), Some(user_quote), Some(treasury_quote)) = (
+                ctx.accounts.pool_quote_token_account.as_ref(),
+                ctx.accounts.user_quote_token_account.as_ref(),
+                ctx.accounts.treasury_quote_token_account.as_ref(),
+            ) else {
+                return err!(CustomError::MissingQuoteAccounts);
+            };
+            Some((pool_quote, user_quote, treasury_quote))
+        }
+        None => None,
+    };
+    // Wrapped SOL stands in for lamports, so it only applies to SOL-quoted pools
+    if ctx.accounts.user_wsol_account.is_some() {
+        require!(quote_accounts.is_none(), CustomError::NativeQuoteOnly);
+    }
+    let (pool_mint, pool_bump) = (pool.token_one, pool.bump);
+    let pool_seeds: &[&[u8]] = &[
+        LiquidityPool::POOL_SEED_PREFIX.as_bytes(),
+        pool_mint.as_ref(),
+        &[pool_bump],
+    ];
+
+    // Public trading only opens at the scheduled start, once the allowlisted presale is
+    // over and a fair launch is cleared
+    pool.ensure_public_trading(clock.unix_timestamp)?;
+
     msg!("Mint: {:?} ", ctx.accounts.mint_token_one.key());
     msg!("Swap: {:?} {:?} {:?}", ctx.accounts.user.key(), style, amount);
 
-    // Compute fee-adjusted amount
-    // Compute fee-adjusted amount: amount * (10000 - fees) / 10000
-    // fees and paperhand_tax_bps are both in basis points
-    let fees_bps = config.fees as u128;
-    let adjusted_amount = (amount as u128)
-        .checked_mul(10000u128.checked_sub(fees_bps).ok_or(CustomError::MathOverflow)?)
-        .ok_or(CustomError::MathOverflow)?
-        .checked_div(10000)
-        .ok_or(CustomError::MathOverflow)? as u64;
-
     if style == 1 {
         // SELL: User sells tokens for SOL
         // style == 1 means user sends tokens to pool and receives SOL
-        
-        // Calculate SOL output before any tax
-        // Uses EFFECTIVE SOL reserve (real + virtual) for price calculation
-        // Constant product: (reserve_one + adjusted_amount) * (effective_sol - amount_out) = reserve_one * effective_sol
-        
-        let reserve_one = pool.reserve_one as u128;
-        let effective_sol = pool.effective_sol_reserve() as u128;  // real + virtual
-        let adjusted_amt = adjusted_amount as u128;
 
-        let denominator = reserve_one.checked_add(adjusted_amt).ok_or(CustomError::OverflowOrUnderflowOccurred)?;
-        let k = reserve_one.checked_mul(effective_sol).ok_or(CustomError::MathOverflow)?;
+        // Sells stay open during a wind-down (SellOnly) but not a full pause
+        config.pause_state.ensure_sells_allowed()?;
+        pool.pause_state.ensure_sells_allowed()?;
+
+        // The creator may have locked their own sells and opted into a higher tax
+        if pool.is_creator(&ctx.accounts.user.key()) {
+            pool.creator_rules.ensure_sells_unlocked(clock.unix_timestamp)?;
+        }
+        let tax_bps = pool.paperhand_tax_bps_for(&ctx.accounts.user.key(), config.paperhand_tax_bps);
         
-        let new_effective_sol = k.checked_div(denominator).ok_or(CustomError::MathOverflow)?;
-        let sol_out_before_tax = (effective_sol.checked_sub(new_effective_sol).ok_or(CustomError::MathOverflow)?) as u64;
+        // Sells are priced on real + virtual SOL but paid from real SOL only: cap them
+        // at what the pool holds, selling only part of `amount` if the user allows it
+        let requested_amount = amount;
+        let amount = pool.fillable_sell_amount(amount, config.fees, allow_partial_fill)?;
+
+        // Calculate SOL output before any tax (fee-adjusted, real + virtual SOL reserve)
+        let sol_out_before_tax = pool.quote_sell(amount, config.fees)?;
         
-        // Slippage Check
-        if sol_out_before_tax < min_amount_out {
+        // Slippage Check (pro rata for a partial fill: same minimum average price)
+        if sol_out_before_tax < pro_rata_min_out(min_amount_out, amount, requested_amount)? {
             return err!(CustomError::SlippageExceeded);
         }
 
@@ -94,15 +178,9 @@ pub fn swap(ctx: Context<Swap>, amount: u64, style: u64, min_amount_out: u64) ->
         let cost_basis_for_sale = position.calculate_cost_basis_for_sale(amount)?;
 
         // Determine if this is a loss (sol_out < cost_basis)
-        let (sol_to_user, tax_amount) = if sol_out_before_tax < cost_basis_for_sale {
+        let tax = calculate_paperhand_tax(sol_out_before_tax, cost_basis_for_sale, tax_bps)?;
+        let (sol_to_user, tax_amount) = if tax > 0 {
             // It's a loss! Apply PaperHandBitchTax
-            // tax = sol_out_before_tax * paperhand_tax_bps / 10000
-            let tax = (sol_out_before_tax as u128)
-                .checked_mul(config.paperhand_tax_bps as u128)
-                .ok_or(CustomError::MathOverflow)?
-                .checked_div(10000_u128)
-                .ok_or(CustomError::MathOverflow)? as u64;
-
             let user_receives = sol_out_before_tax
                 .checked_sub(tax)
                 .ok_or(CustomError::OverflowOrUnderflowOccurred)?;
@@ -126,18 +204,24 @@ pub fn swap(ctx: Context<Swap>, amount: u64, style: u64, min_amount_out: u64) ->
             (sol_out_before_tax, 0_u64)
         };
 
+        // The referrer's slice of the trading fee leaves the pool on top of the proceeds
+        let referral_fee = match ctx.accounts.referrer.as_ref() {
+            Some(_) => calculate_referral_fee(
+                pool.sell_fee(amount, config.fees)?,
+                config.referral_fee_bps,
+            )?
+            // Never let the referral push the payout past the real SOL reserve
+            .min(pool.reserve_two.saturating_sub(sol_out_before_tax)),
+            None => 0,
+        };
+
+        let sol_from_pool = sol_out_before_tax.checked_add(referral_fee).ok_or(CustomError::MathOverflow)?;
+        pool.ensure_sell_impact_within(amount, sol_from_pool, max_price_impact_bps)?;
+
         // Update pool reserves
-        let new_reserves_one = pool.reserve_one
-            .checked_add(amount)
-            .ok_or(CustomError::OverflowOrUnderflowOccurred)?;
-        let new_reserves_two = pool.reserve_two
-            .checked_sub(sol_out_before_tax)
-            .ok_or(CustomError::OverflowOrUnderflowOccurred)?;
-        
-        pool.reserve_one = new_reserves_one;
-        pool.reserve_two = new_reserves_two;
+        pool.apply_sell(amount, sol_from_pool)?;
 
-        msg!("Reserves: {:?} {:?}", new_reserves_one, new_reserves_two);
+        msg!("Reserves: {:?} {:?}", pool.reserve_one, pool.reserve_two);
 
         // Transfer tokens from user to pool
         token::transfer(
@@ -152,21 +236,58 @@ pub fn swap(ctx: Context<Swap>, amount: u64, style: u64, min_amount_out: u64) ->
             amount,
         )?;
 
-        // Transfer SOL from global account to user
-        system_program::transfer(
-            CpiContext::new_with_signer(
-                ctx.accounts.system_program.to_account_info(),
-                system_program::Transfer {
-                    from: ctx.accounts.global_account.to_account_info(),
-                    to: ctx.accounts.user.to_account_info(),
-                },
-                &[&[b"global", &[ctx.bumps.global_account]]],
-            ),
-            sol_to_user,
-        )?;
+        if let Some((pool_quote, user_quote, treasury_quote)) = quote_accounts {
+            // Pay the proceeds and the tax from the pool's quote token account
+            token::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    token::Transfer {
+                        from: pool_quote.to_account_info(),
+                        to: user_quote.to_account_info(),
+                        authority: pool.to_account_info(),
+                    },
+                    &[pool_seeds],
+                ),
+                sol_to_user,
+            )?;
+            if tax_amount > 0 {
+                token::transfer(
+                    CpiContext::new_with_signer(
+                        ctx.accounts.token_program.to_account_info(),
+                        token::Transfer {
+                            from: pool_quote.to_account_info(),
+                            to: treasury_quote.to_account_info(),
+                            authority: pool.to_account_info(),
+                        },
+                        &[pool_seeds],
+                    ),
+                    tax_amount,
+                )?;
+            }
+        } else {
+            // Transfer SOL from global account to user, wrapped if they asked for wSOL
+            let proceeds_to = match ctx.accounts.user_wsol_account.as_ref() {
+                Some(wsol) => wsol.to_account_info(),
+                None => ctx.accounts.user.to_account_info(),
+            };
+            system_program::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: ctx.accounts.global_account.to_account_info(),
+                        to: proceeds_to.clone(),
+                    },
+                    &[&[b"global", &[ctx.bumps.global_account]]],
+                ),
+                sol_to_user,
+            )?;
+            if ctx.accounts.user_wsol_account.is_some() {
+                sync_wsol(&ctx.accounts.token_program.to_account_info(), &proceeds_to)?;
+            }
+        }
 
         // If there's tax, transfer it to treasury
-        if tax_amount > 0 {
+        if tax_amount > 0 && quote_accounts.is_none() {
             system_program::transfer(
                 CpiContext::new_with_signer(
                     ctx.accounts.system_program.to_account_info(),
@@ -180,6 +301,23 @@ pub fn swap(ctx: Context<Swap>, amount: u64, style: u64, min_amount_out: u64) ->
             )?;
         }
 
+        if let Some(referrer) = ctx.accounts.referrer.as_mut() {
+            if referral_fee > 0 {
+                system_program::transfer(
+                    CpiContext::new_with_signer(
+                        ctx.accounts.system_program.to_account_info(),
+                        system_program::Transfer {
+                            from: ctx.accounts.global_account.to_account_info(),
+                            to: referrer.to_account_info(),
+                        },
+                        &[&[b"global", &[ctx.bumps.global_account]]],
+                    ),
+                    referral_fee,
+                )?;
+                referrer.record_fee(referral_fee)?;
+            }
+        }
+
         // Update user position
         position.record_sell(amount, cost_basis_for_sale)?;
 
@@ -190,6 +328,8 @@ pub fn swap(ctx: Context<Swap>, amount: u64, style: u64, min_amount_out: u64) ->
             side: "sell".to_string(),
             token_amount: amount,
             sol_amount: sol_to_user,
+            referrer: ctx.accounts.referrer.as_ref().map(|r| r.owner),
+            referral_fee,
             timestamp: clock.unix_timestamp,
         });
 
@@ -200,39 +340,66 @@ pub fn swap(ctx: Context<Swap>, amount: u64, style: u64, min_amount_out: u64) ->
             total_sol: position.total_sol,
         });
 
+        // Return the position's rent once the user has fully exited, if requested. During
+        // the launch phase the position is kept (the sell still goes through) so its
+        // window counters survive.
+        if close_empty_position
+            && position.total_tokens == 0
+            && pool.ensure_positions_closable(clock.unix_timestamp).is_ok()
+        {
+            let rent_returned = position.to_account_info().lamports();
+            position.close(ctx.accounts.user.to_account_info())?;
+
+            emit!(PositionClosed {
+                user: ctx.accounts.user.key(),
+                pool: pool.key(),
+                rent_returned,
+            });
+        }
+
+        Ok(SwapResult {
+            amount_in: amount,
+            amount_out: sol_to_user,
+            paperhand_tax: tax_amount,
+            referral_fee,
+        })
     } else {
         // BUY: User sends SOL to buy tokens
         // style == 2 (or any other) means user sends SOL and receives tokens
-        
-        // Uses EFFECTIVE SOL reserve (real + virtual) for price calculation
-        
-        let reserve_one = pool.reserve_one as u128;
-        let effective_sol = pool.effective_sol_reserve() as u128;  // real + virtual
-        let adjusted_amt = adjusted_amount as u128;
 
-        let denominator = effective_sol.checked_add(adjusted_amt).ok_or(CustomError::OverflowOrUnderflowOccurred)?;
-        let k = reserve_one.checked_mul(effective_sol).ok_or(CustomError::MathOverflow)?;
+        config.pause_state.ensure_buys_allowed()?;
+        pool.pause_state.ensure_buys_allowed()?;
         
-        let new_reserve_one = k.checked_div(denominator).ok_or(CustomError::MathOverflow)?;
-        let tokens_out = (reserve_one.checked_sub(new_reserve_one).ok_or(CustomError::MathOverflow)?) as u64;
+        // Uses EFFECTIVE SOL reserve (real + virtual) for price calculation
+        let tokens_out = pool.quote_buy(amount, config.fees)?;
 
         // Slippage Check
         if tokens_out < min_amount_out {
             return err!(CustomError::SlippageExceeded);
         }
 
-        // Update reserves
-        let new_reserves_one = pool.reserve_one
-            .checked_sub(tokens_out)
-            .ok_or(CustomError::OverflowOrUnderflowOccurred)?;
-        let new_reserves_two = pool.reserve_two
-            .checked_add(amount)
-            .ok_or(CustomError::OverflowOrUnderflowOccurred)?;
+        // Anti-sniper limits during the launch window
+        if pool.launch_protection.is_active(clock.unix_timestamp) {
+            let price_impact_bps = pool.buy_price_impact_bps(tokens_out, amount)?;
+            pool.launch_protection.enforce_buy(position, amount, tokens_out, price_impact_bps)?;
+        }
 
-        pool.reserve_one = new_reserves_one;
-        pool.reserve_two = new_reserves_two;
+        // The referrer's slice of the trading fee goes to them instead of the pool
+        let referral_fee = match ctx.accounts.referrer.as_ref() {
+            Some(_) => calculate_referral_fee(
+                LiquidityPool::buy_fee(amount, config.fees)?,
+                config.referral_fee_bps,
+            )?,
+            None => 0,
+        };
+        let sol_to_pool = amount - referral_fee;
+
+        pool.ensure_buy_impact_within(tokens_out, sol_to_pool, max_price_impact_bps)?;
+
+        // Update reserves
+        pool.apply_buy(tokens_out, sol_to_pool)?;
 
-        msg!("Reserves: {:?} {:?}", new_reserves_one, new_reserves_two);
+        msg!("Reserves: {:?} {:?}", pool.reserve_one, pool.reserve_two);
 
         // Transfer tokens from pool to user
         token::transfer(
@@ -248,17 +415,71 @@ pub fn swap(ctx: Context<Swap>, amount: u64, style: u64, min_amount_out: u64) ->
             tokens_out,
         )?;
 
-        // Transfer SOL from user to global account
-        system_program::transfer(
-            CpiContext::new(
-                ctx.accounts.system_program.to_account_info(),
-                system_program::Transfer {
-                    from: ctx.accounts.user.to_account_info(),
-                    to: ctx.accounts.global_account.to_account_info(),
-                },
-            ),
-            amount,
-        )?;
+        if let Some((pool_quote, user_quote, _)) = quote_accounts {
+            // Transfer the quote token from user to the pool's quote token account
+            token::transfer(
+                CpiContext::new(
+                    ctx.accounts.token_program.to_account_info(),
+                    token::Transfer {
+                        from: user_quote.to_account_info(),
+                        to: pool_quote.to_account_info(),
+                        authority: ctx.accounts.user.to_account_info(),
+                    },
+                ),
+                sol_to_pool,
+            )?;
+        } else {
+            // Paying in wSOL: unwrap it onto the user's wallet first, then pay as usual
+            if let Some(wsol) = ctx.accounts.user_wsol_account.as_ref() {
+                let (Some(temp_account), Some(temp_bump), Some(mint)) = (
+                    ctx.accounts.wsol_temp_account.as_ref(),
+                    ctx.bumps.wsol_temp_account,
+                    ctx.accounts.native_mint.as_ref(),
+                ) else {
+                    return err!(CustomError::MissingWsolAccounts);
+                };
+                unwrap_wsol(
+                    UnwrapWsol {
+                        user: &ctx.accounts.user.to_account_info(),
+                        user_wsol_account: &wsol.to_account_info(),
+                        temp_account: &temp_account.to_account_info(),
+                        temp_bump,
+                        native_mint: &mint.to_account_info(),
+                        system_program: &ctx.accounts.system_program.to_account_info(),
+                        token_program: &ctx.accounts.token_program.to_account_info(),
+                    },
+                    amount,
+                )?;
+            }
+
+            // Transfer SOL from user to global account
+            system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: ctx.accounts.user.to_account_info(),
+                        to: ctx.accounts.global_account.to_account_info(),
+                    },
+                ),
+                sol_to_pool,
+            )?;
+        }
+
+        if let Some(referrer) = ctx.accounts.referrer.as_mut() {
+            if referral_fee > 0 {
+                system_program::transfer(
+                    CpiContext::new(
+                        ctx.accounts.system_program.to_account_info(),
+                        system_program::Transfer {
+                            from: ctx.accounts.user.to_account_info(),
+                            to: referrer.to_account_info(),
+                        },
+                    ),
+                    referral_fee,
+                )?;
+                referrer.record_fee(referral_fee)?;
+            }
+        }
 
         // Update user position: record the buy
         // We track the actual SOL spent (before fees go elsewhere, this is the user's cost)
@@ -271,6 +492,8 @@ pub fn swap(ctx: Context<Swap>, amount: u64, style: u64, min_amount_out: u64) ->
             side: "buy".to_string(),
             token_amount: tokens_out,
             sol_amount: amount,
+            referrer: ctx.accounts.referrer.as_ref().map(|r| r.owner),
+            referral_fee,
             timestamp: clock.unix_timestamp,
         });
 
@@ -280,9 +503,14 @@ pub fn swap(ctx: Context<Swap>, amount: u64, style: u64, min_amount_out: u64) ->
             total_tokens: position.total_tokens,
             total_sol: position.total_sol,
         });
-    }
 
-    Ok(())
+        Ok(SwapResult {
+            amount_in: amount,
+            amount_out: tokens_out,
+            paperhand_tax: 0,
+            referral_fee,
+        })
+    }
 }
 
 #[derive(Accounts)]
@@ -336,17 +564,73 @@ pub struct Swap<'info> {
     )]
     pub pool_token_account_one: Box<Account<'info, TokenAccount>>,
 
+    /// Any token account of the user for this mint (not necessarily the associated one),
+    /// so PDAs trading through CPI can use their own token accounts
     #[account(
         mut,
-        associated_token::mint = mint_token_one,
-        associated_token::authority = user,
+        token::mint = mint_token_one,
+        token::authority = user,
     )]
     pub user_token_account_one: Box<Account<'info, TokenAccount>>,
 
+    /// Referrer credited with `referral_fee_bps` of the trading fee, if the trade was referred
+    #[account(
+        mut,
+        seeds = [Referrer::SEED_PREFIX.as_bytes(), referrer.owner.as_ref()],
+        bump = referrer.bump,
+    )]
+    pub referrer: Option<Box<Account<'info, Referrer>>>,
+
+    /// Trader: a wallet, or a PDA signing through CPI (it also pays for a new position)
     #[account(mut)]
     pub user: Signer<'info>,
     pub rent: Sysvar<'info, Rent>,
     pub system_program: Program<'info, System>,
     pub token_program: Program<'info, Token>,
-    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    /// Pool's quote token account, only for pools quoted in an SPL token
+    #[account(
+        mut,
+        address = get_associated_token_address(&pool.key(), &pool.token_two),
+    )]
+    pub pool_quote_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// User's quote token account, only for pools quoted in an SPL token
+    #[account(
+        mut,
+        token::mint = pool.token_two,
+        token::authority = user,
+    )]
+    pub user_quote_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// Treasury's quote token account receiving the PaperHand tax, only for pools
+    /// quoted in an SPL token
+    #[account(
+        mut,
+        token::mint = pool.token_two,
+        token::authority = dex_configuration_account.treasury,
+    )]
+    pub treasury_quote_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// User's wSOL account, to pay (buy) or be paid (sell) in wrapped SOL instead of
+    /// lamports on SOL-quoted pools
+    #[account(
+        mut,
+        token::mint = native_mint::ID,
+        token::authority = user,
+    )]
+    pub user_wsol_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// CHECK: Empty PDA used to unwrap the user's wSOL on a buy; created and closed
+    /// within the instruction
+    #[account(
+        mut,
+        seeds = [WSOL_TEMP_SEED_PREFIX.as_bytes(), user.key().as_ref()],
+        bump,
+    )]
+    pub wsol_temp_account: Option<UncheckedAccount<'info>>,
+
+    /// Native mint, needed to unwrap wSOL on a buy
+    #[account(address = native_mint::ID)]
+    pub native_mint: Option<Box<Account<'info, Mint>>>,
 }
@@ -0,0 +1,295 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token::{self, Mint, Token, TokenAccount};
+
+use crate::{
+    errors::CustomError,
+    instructions::swap::{
+        ensure_not_expired, PaperhandTaxApplied, PositionUpdated, SwapResult, TradeExecuted,
+    },
+    state::{
+        calculate_paperhand_tax, pro_rata_min_out, CurveConfiguration, LiquidityPool,
+        UserPosition,
+    },
+};
+
+/// Sell `amount_in` of token A and buy token B with the proceeds, in one instruction.
+///
+/// The A leg is a regular sell, PaperHand tax included. The SOL left after the tax never
+/// leaves the global vault: it moves from pool A's reserve to pool B's, and is recorded as
+/// the cost basis of the B tokens. `min_amount_out` applies to the B tokens received.
+/// The A leg is capped at pool A's real SOL like a `swap` sell, and `max_price_impact_bps`
+/// bounds each leg.
+#[allow(clippy::too_many_arguments)]
+pub fn swap_token_to_token(
+    ctx: Context<SwapTokenToToken>,
+    amount_in: u64,
+    min_amount_out: u64,
+    deadline_unix_ts: Option<i64>,
+    max_slot: Option<u64>,
+    max_price_impact_bps: Option<u16>,
+    allow_partial_fill: bool,
+) -> Result<SwapResult> {
+    require!(amount_in > 0, CustomError::InvalidAmount);
+
+    let config = &ctx.accounts.dex_configuration_account;
+    let pool_a = &mut ctx.accounts.pool_a;
+    let pool_b = &mut ctx.accounts.pool_b;
+    let position_a = &mut ctx.accounts.user_position_a;
+    let position_b = &mut ctx.accounts.user_position_b;
+    let user = ctx.accounts.user.key();
+
+    if position_b.owner == Pubkey::default() {
+        position_b.set_inner(UserPosition::new(pool_b.key(), user, ctx.bumps.user_position_b));
+    }
+
+    let clock = Clock::get()?;
+    ensure_not_expired(&clock, deadline_unix_ts, max_slot)?;
+
+    // Leg A's SOL funds leg B inside the global vault, so both pools must be SOL-quoted
+    require!(
+        pool_a.quote_mint().is_none() && pool_b.quote_mint().is_none(),
+        CustomError::NativeQuoteOnly
+    );
+
+    pool_a.ensure_public_trading(clock.unix_timestamp)?;
+    pool_b.ensure_public_trading(clock.unix_timestamp)?;
+    config.pause_state.ensure_sells_allowed()?;
+    pool_a.pause_state.ensure_sells_allowed()?;
+    config.pause_state.ensure_buys_allowed()?;
+    pool_b.pause_state.ensure_buys_allowed()?;
+
+    msg!("Swap: {:?} {:?} -> {:?}", user, amount_in, ctx.accounts.mint_b.key());
+
+    // Leg A: sell for SOL, taxed like a `swap` sell
+    if pool_a.is_creator(&user) {
+        pool_a.creator_rules.ensure_sells_unlocked(clock.unix_timestamp)?;
+    }
+    let tax_bps = pool_a.paperhand_tax_bps_for(&user, config.paperhand_tax_bps);
+
+    // Pool A pays out of its real SOL only, so the sell is capped as in `swap`
+    let requested_amount = amount_in;
+    let amount_in = pool_a.fillable_sell_amount(amount_in, config.fees, allow_partial_fill)?;
+
+    let sol_out_before_tax = pool_a.quote_sell(amount_in, config.fees)?;
+    require!(position_a.total_tokens >= amount_in, CustomError::InsufficientPosition);
+    pool_a.ensure_sell_impact_within(amount_in, sol_out_before_tax, max_price_impact_bps)?;
+    let cost_basis_for_sale = position_a.calculate_cost_basis_for_sale(amount_in)?;
+    let tax = calculate_paperhand_tax(sol_out_before_tax, cost_basis_for_sale, tax_bps)?;
+    let sol_in_b = sol_out_before_tax
+        .checked_sub(tax)
+        .ok_or(CustomError::OverflowOrUnderflowOccurred)?;
+    require!(sol_in_b > 0, CustomError::InvalidAmount);
+
+    // Leg B: buy with the SOL actually left after the tax
+    let tokens_out = pool_b.quote_buy(sol_in_b, config.fees)?;
+    // Pro rata for a partial fill of leg A: same minimum average rate
+    require!(
+        tokens_out >= pro_rata_min_out(min_amount_out, amount_in, requested_amount)?,
+        CustomError::SlippageExceeded
+    );
+    pool_b.ensure_buy_impact_within(tokens_out, sol_in_b, max_price_impact_bps)?;
+
+    if pool_b.launch_protection.is_active(clock.unix_timestamp) {
+        let price_impact_bps = pool_b.buy_price_impact_bps(tokens_out, sol_in_b)?;
+        pool_b.launch_protection.enforce_buy(position_b, sol_in_b, tokens_out, price_impact_bps)?;
+    }
+
+    // The global vault holds both pools' SOL, so only the reserves move
+    pool_a.apply_sell(amount_in, sol_out_before_tax)?;
+    pool_b.apply_buy(tokens_out, sol_in_b)?;
+
+    msg!("Reserves A: {:?} {:?}", pool_a.reserve_one, pool_a.reserve_two);
+    msg!("Reserves B: {:?} {:?}", pool_b.reserve_one, pool_b.reserve_two);
+
+    let signer_seeds: &[&[&[u8]]] = &[&[b"global", &[ctx.bumps.global_account]]];
+
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            token::Transfer {
+                from: ctx.accounts.user_token_account_a.to_account_info(),
+                to: ctx.accounts.pool_token_account_a.to_account_info(),
+                authority: ctx.accounts.user.to_account_info(),
+            },
+        ),
+        amount_in,
+    )?;
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token::Transfer {
+                from: ctx.accounts.pool_token_account_b.to_account_info(),
+                to: ctx.accounts.user_token_account_b.to_account_info(),
+                authority: ctx.accounts.global_account.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        tokens_out,
+    )?;
+
+    if tax > 0 {
+        msg!("PaperHandBitchTax applied! Cost basis: {}, SOL out: {}, Tax: {}",
+            cost_basis_for_sale, sol_out_before_tax, tax);
+
+        system_program::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.global_account.to_account_info(),
+                    to: ctx.accounts.treasury_vault.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            tax,
+        )?;
+
+        emit!(PaperhandTaxApplied {
+            user,
+            pool: pool_a.key(),
+            sol_out_before_tax,
+            cost_basis_for_sale,
+            tax,
+            sol_to_user: sol_in_b,
+        });
+    }
+
+    position_a.record_sell(amount_in, cost_basis_for_sale)?;
+    position_b.record_buy(tokens_out, sol_in_b)?;
+
+    emit!(TradeExecuted {
+        user,
+        pool: pool_a.key(),
+        side: "sell".to_string(),
+        token_amount: amount_in,
+        sol_amount: sol_in_b,
+        referrer: None,
+        referral_fee: 0,
+        timestamp: clock.unix_timestamp,
+    });
+    emit!(TradeExecuted {
+        user,
+        pool: pool_b.key(),
+        side: "buy".to_string(),
+        token_amount: tokens_out,
+        sol_amount: sol_in_b,
+        referrer: None,
+        referral_fee: 0,
+        timestamp: clock.unix_timestamp,
+    });
+
+    emit!(PositionUpdated {
+        user,
+        pool: pool_a.key(),
+        total_tokens: position_a.total_tokens,
+        total_sol: position_a.total_sol,
+    });
+    emit!(PositionUpdated {
+        user,
+        pool: pool_b.key(),
+        total_tokens: position_b.total_tokens,
+        total_sol: position_b.total_sol,
+    });
+
+    Ok(SwapResult {
+        amount_in,
+        amount_out: tokens_out,
+        paperhand_tax: tax,
+        referral_fee: 0,
+    })
+}
+
+#[derive(Accounts)]
+pub struct SwapTokenToToken<'info> {
+    #[account(
+        seeds = [CurveConfiguration::SEED.as_bytes()],
+        bump,
+    )]
+    pub dex_configuration_account: Box<Account<'info, CurveConfiguration>>,
+
+    /// Pool of the token being sold
+    #[account(
+        mut,
+        seeds = [LiquidityPool::POOL_SEED_PREFIX.as_bytes(), mint_a.key().as_ref()],
+        bump = pool_a.bump
+    )]
+    pub pool_a: Box<Account<'info, LiquidityPool>>,
+
+    /// Pool of the token being bought
+    #[account(
+        mut,
+        seeds = [LiquidityPool::POOL_SEED_PREFIX.as_bytes(), mint_b.key().as_ref()],
+        bump = pool_b.bump,
+        constraint = mint_a.key() != mint_b.key() @ CustomError::DuplicateTokenNotAllowed,
+    )]
+    pub pool_b: Box<Account<'info, LiquidityPool>>,
+
+    /// CHECK: Global SOL vault PDA
+    #[account(
+        mut,
+        seeds = [b"global"],
+        bump,
+    )]
+    pub global_account: AccountInfo<'info>,
+
+    /// CHECK: Treasury account that receives paperhand taxes
+    #[account(
+        mut,
+        constraint = treasury_vault.key() == dex_configuration_account.treasury
+    )]
+    pub treasury_vault: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [UserPosition::SEED_PREFIX.as_bytes(), pool_a.key().as_ref(), user.key().as_ref()],
+        bump = user_position_a.bump,
+    )]
+    pub user_position_a: Box<Account<'info, UserPosition>>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = UserPosition::ACCOUNT_SIZE,
+        seeds = [UserPosition::SEED_PREFIX.as_bytes(), pool_b.key().as_ref(), user.key().as_ref()],
+        bump,
+    )]
+    pub user_position_b: Box<Account<'info, UserPosition>>,
+
+    pub mint_a: Box<Account<'info, Mint>>,
+
+    pub mint_b: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint_a,
+        associated_token::authority = global_account
+    )]
+    pub pool_token_account_a: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint_b,
+        associated_token::authority = global_account
+    )]
+    pub pool_token_account_b: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = mint_a,
+        token::authority = user,
+    )]
+    pub user_token_account_a: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = mint_b,
+        token::authority = user,
+    )]
+    pub user_token_account_b: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+}
@@ -1,37 +1,227 @@
 use anchor_lang::prelude::*;
-use crate::state::CurveConfiguration;
+use crate::state::{ConfigChange, CurveConfiguration, PendingConfigChange, SymbolPolicy};
 use crate::errors::CustomError;
 
+/// Event emitted when the admin queues a configuration change
+#[event]
+pub struct ConfigChangeQueued {
+    pub config: Pubkey,
+    pub proposer: Pubkey,
+    pub change: ConfigChange,
+    pub eta: i64,
+}
+
+/// Event emitted when a queued configuration change is cancelled
+#[event]
+pub struct ConfigChangeCancelled {
+    pub config: Pubkey,
+    pub cancelled_by: Pubkey,
+    pub change: ConfigChange,
+}
+
+/// Old and new value of a changed `u16` field
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct U16Change {
+    pub old: u16,
+    pub new: u16,
+}
+
+/// Old and new value of a changed `u64` field
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct U64Change {
+    pub old: u64,
+    pub new: u64,
+}
+
+/// Old and new value of a changed `i64` field
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct I64Change {
+    pub old: i64,
+    pub new: i64,
+}
+
+/// Old and new value of a changed `Pubkey` field
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct PubkeyChange {
+    pub old: Pubkey,
+    pub new: Pubkey,
+}
+
+/// Old and new symbol uniqueness policy
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct SymbolPolicyChange {
+    pub old: SymbolPolicy,
+    pub new: SymbolPolicy,
+}
+
+/// Event emitted when a queued configuration change is applied.
+/// Every field that was updated carries its old and new value; untouched fields are `None`.
+#[event]
+pub struct ConfigUpdated {
+    pub config: Pubkey,
+    pub fees: Option<U16Change>,
+    pub treasury: Option<PubkeyChange>,
+    pub paperhand_tax_bps: Option<U16Change>,
+    pub config_timelock_secs: Option<I64Change>,
+    pub pauser: Option<PubkeyChange>,
+    pub admin: Option<PubkeyChange>,
+    pub default_virtual_sol: Option<U64Change>,
+    pub max_protection_window_secs: Option<I64Change>,
+    pub min_protection_buy_sol: Option<U64Change>,
+    pub max_creator_allocation_bps: Option<U16Change>,
+    pub min_start_price: Option<U64Change>,
+    pub max_start_price: Option<U64Change>,
+    pub launch_fee_lamports: Option<U64Change>,
+    pub symbol_policy: Option<SymbolPolicyChange>,
+    pub referral_fee_bps: Option<U16Change>,
+    pub timestamp: i64,
+}
+
+/// Queue a configuration change.
+///
+/// Changes are not applied immediately: they are stored in a `PendingConfigChange`
+/// account and can only be applied once `config_timelock_secs` has elapsed, giving
+/// traders time to exit before unfavourable parameters land.
 pub fn update_configuration(
     ctx: Context<UpdateCurveConfiguration>,
-    new_fees: Option<u16>,
-    new_treasury: Option<Pubkey>,
-    new_paperhand_tax_bps: Option<u16>,
+    change: ConfigChange,
 ) -> Result<()> {
-    let dex_config = &mut ctx.accounts.dex_configuration_account;
+    let dex_config = &ctx.accounts.dex_configuration_account;
 
     // Admin validation
     require!(ctx.accounts.admin.key() == dex_config.admin, CustomError::Unauthorized);
 
-    if let Some(fees) = new_fees {
-        if fees > 10000 {
-            return err!(CustomError::InvalidFee);
-        }
-        dex_config.fees = fees;
-    }
+    change.validate_for(dex_config)?;
+
+    let clock = Clock::get()?;
+    let eta = clock
+        .unix_timestamp
+        .checked_add(dex_config.config_timelock_secs)
+        .ok_or(CustomError::MathOverflow)?;
 
-    if let Some(treasury) = new_treasury {
-        dex_config.treasury = treasury;
-    }
+    let pending = &mut ctx.accounts.pending_config_change;
+    pending.set_inner(PendingConfigChange {
+        config: dex_config.key(),
+        proposer: ctx.accounts.admin.key(),
+        change: change.clone(),
+        queued_at: clock.unix_timestamp,
+        eta,
+        bump: ctx.bumps.pending_config_change,
+    });
 
-    if let Some(tax_bps) = new_paperhand_tax_bps {
-        if tax_bps > 10000 {
-            return err!(CustomError::InvalidTaxBps);
-        }
-        dex_config.paperhand_tax_bps = tax_bps;
-    }
+    msg!("Configuration change queued by admin: {:?}, eta: {}", ctx.accounts.admin.key(), eta);
 
-    msg!("Configuration updated by admin: {:?}", ctx.accounts.admin.key());
+    emit!(ConfigChangeQueued {
+        config: dex_config.key(),
+        proposer: ctx.accounts.admin.key(),
+        change,
+        eta,
+    });
+
+    Ok(())
+}
+
+/// Apply a queued configuration change once its timelock has expired.
+/// Permissionless: anyone can crank a change whose ETA has passed.
+pub fn apply_configuration_update(ctx: Context<ApplyConfigurationUpdate>) -> Result<()> {
+    let pending = &ctx.accounts.pending_config_change;
+    let clock = Clock::get()?;
+
+    require!(clock.unix_timestamp >= pending.eta, CustomError::TimelockNotExpired);
+
+    let change = pending.change.clone();
+    let dex_config = &mut ctx.accounts.dex_configuration_account;
+    // Re-validate against the current configuration, which may have changed since queuing
+    change.validate_for(dex_config)?;
+
+    let config_key = dex_config.key();
+
+    // Apply each field while capturing its previous value for the audit trail
+    let updated = ConfigUpdated {
+        config: config_key,
+        fees: change.fees.map(|new| U16Change {
+            old: std::mem::replace(&mut dex_config.fees, new),
+            new,
+        }),
+        treasury: change.treasury.map(|new| PubkeyChange {
+            old: std::mem::replace(&mut dex_config.treasury, new),
+            new,
+        }),
+        paperhand_tax_bps: change.paperhand_tax_bps.map(|new| U16Change {
+            old: std::mem::replace(&mut dex_config.paperhand_tax_bps, new),
+            new,
+        }),
+        config_timelock_secs: change.config_timelock_secs.map(|new| I64Change {
+            old: std::mem::replace(&mut dex_config.config_timelock_secs, new),
+            new,
+        }),
+        pauser: change.pauser.map(|new| PubkeyChange {
+            old: std::mem::replace(&mut dex_config.pauser, new),
+            new,
+        }),
+        admin: change.admin.map(|new| PubkeyChange {
+            old: std::mem::replace(&mut dex_config.admin, new),
+            new,
+        }),
+        default_virtual_sol: change.default_virtual_sol.map(|new| U64Change {
+            old: std::mem::replace(&mut dex_config.default_virtual_sol, new),
+            new,
+        }),
+        max_protection_window_secs: change.max_protection_window_secs.map(|new| I64Change {
+            old: std::mem::replace(&mut dex_config.max_protection_window_secs, new),
+            new,
+        }),
+        min_protection_buy_sol: change.min_protection_buy_sol.map(|new| U64Change {
+            old: std::mem::replace(&mut dex_config.min_protection_buy_sol, new),
+            new,
+        }),
+        max_creator_allocation_bps: change.max_creator_allocation_bps.map(|new| U16Change {
+            old: std::mem::replace(&mut dex_config.max_creator_allocation_bps, new),
+            new,
+        }),
+        min_start_price: change.min_start_price.map(|new| U64Change {
+            old: std::mem::replace(&mut dex_config.min_start_price, new),
+            new,
+        }),
+        max_start_price: change.max_start_price.map(|new| U64Change {
+            old: std::mem::replace(&mut dex_config.max_start_price, new),
+            new,
+        }),
+        launch_fee_lamports: change.launch_fee_lamports.map(|new| U64Change {
+            old: std::mem::replace(&mut dex_config.launch_fee_lamports, new),
+            new,
+        }),
+        symbol_policy: change.symbol_policy.map(|new| SymbolPolicyChange {
+            old: std::mem::replace(&mut dex_config.symbol_policy, new),
+            new,
+        }),
+        referral_fee_bps: change.referral_fee_bps.map(|new| U16Change {
+            old: std::mem::replace(&mut dex_config.referral_fee_bps, new),
+            new,
+        }),
+        timestamp: clock.unix_timestamp,
+    };
+
+    msg!("Configuration change applied");
+    emit!(updated);
+
+    Ok(())
+}
+
+/// Cancel a queued configuration change (admin only)
+pub fn cancel_configuration_update(ctx: Context<CancelConfigurationUpdate>) -> Result<()> {
+    let dex_config = &ctx.accounts.dex_configuration_account;
+
+    // Admin validation
+    require!(ctx.accounts.admin.key() == dex_config.admin, CustomError::Unauthorized);
+
+    msg!("Configuration change cancelled by admin: {:?}", ctx.accounts.admin.key());
+
+    emit!(ConfigChangeCancelled {
+        config: dex_config.key(),
+        cancelled_by: ctx.accounts.admin.key(),
+        change: ctx.accounts.pending_config_change.change.clone(),
+    });
 
     Ok(())
 }
@@ -39,12 +229,67 @@ pub fn update_configuration(
 #[derive(Accounts)]
 pub struct UpdateCurveConfiguration<'info> {
     #[account(
-        mut,
         seeds = [CurveConfiguration::SEED.as_bytes()],
         bump,
     )]
     pub dex_configuration_account: Box<Account<'info, CurveConfiguration>>,
 
+    #[account(
+        init,
+        payer = admin,
+        space = PendingConfigChange::ACCOUNT_SIZE,
+        seeds = [PendingConfigChange::SEED.as_bytes(), dex_configuration_account.key().as_ref()],
+        bump,
+    )]
+    pub pending_config_change: Box<Account<'info, PendingConfigChange>>,
+
     #[account(mut, signer)]
     pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ApplyConfigurationUpdate<'info> {
+    #[account(
+        mut,
+        seeds = [CurveConfiguration::SEED.as_bytes()],
+        bump,
+    )]
+    pub dex_configuration_account: Box<Account<'info, CurveConfiguration>>,
+
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [PendingConfigChange::SEED.as_bytes(), dex_configuration_account.key().as_ref()],
+        bump = pending_config_change.bump,
+    )]
+    pub pending_config_change: Box<Account<'info, PendingConfigChange>>,
+
+    /// CHECK: Receives the pending change rent back; must match the recorded proposer
+    #[account(mut, address = pending_config_change.proposer)]
+    pub proposer: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CancelConfigurationUpdate<'info> {
+    #[account(
+        seeds = [CurveConfiguration::SEED.as_bytes()],
+        bump,
+    )]
+    pub dex_configuration_account: Box<Account<'info, CurveConfiguration>>,
+
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [PendingConfigChange::SEED.as_bytes(), dex_configuration_account.key().as_ref()],
+        bump = pending_config_change.bump,
+    )]
+    pub pending_config_change: Box<Account<'info, PendingConfigChange>>,
+
+    /// CHECK: Receives the pending change rent back; must match the recorded proposer
+    #[account(mut, address = pending_config_change.proposer)]
+    pub proposer: AccountInfo<'info>,
+
+    #[account(signer)]
+    pub admin: Signer<'info>,
 }
@@ -0,0 +1,126 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{self, Mint, Token, TokenAccount},
+};
+
+use crate::{
+    errors::CustomError,
+    instructions::swap::PositionUpdated,
+    state::{CreatorVesting, UserPosition},
+};
+
+/// Event emitted when a creator claims unlocked tokens
+#[event]
+pub struct VestedTokensClaimed {
+    pub creator: Pubkey,
+    pub pool: Pubkey,
+    pub amount: u64,
+    pub total_claimed: u64,
+    pub timestamp: i64,
+}
+
+/// Claim the unlocked part of the creator allocation.
+///
+/// Claimed tokens are added to the creator's position at the launch price, so they
+/// can be sold through `swap` like any other holding and still count for the PaperHand tax.
+pub fn claim_vested(ctx: Context<ClaimVested>) -> Result<()> {
+    let vesting = &mut ctx.accounts.creator_vesting;
+    let clock = Clock::get()?;
+
+    let amount = vesting.claimable_amount(clock.unix_timestamp)?;
+    require!(amount > 0, CustomError::NothingToClaim);
+
+    vesting.claimed_amount = vesting.claimed_amount
+        .checked_add(amount)
+        .ok_or(CustomError::MathOverflow)?;
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token::Transfer {
+                from: ctx.accounts.vesting_token_account.to_account_info(),
+                to: ctx.accounts.creator_token_account.to_account_info(),
+                authority: vesting.to_account_info(),
+            },
+            &[&[CreatorVesting::SEED_PREFIX.as_bytes(), vesting.pool.as_ref(), &[vesting.bump]]],
+        ),
+        amount,
+    )?;
+
+    // Freshly created position (init_if_needed): record its identity and layout version
+    let position = &mut ctx.accounts.creator_position;
+    if position.owner == Pubkey::default() {
+        position.set_inner(UserPosition::new(
+            vesting.pool,
+            ctx.accounts.creator.key(),
+            ctx.bumps.creator_position,
+        ));
+    }
+    let cost_basis = vesting.cost_basis_for(amount, ctx.accounts.mint.decimals)?;
+    position.record_buy(amount, cost_basis)?;
+
+    msg!("Vested tokens claimed: {:?} {:?}", ctx.accounts.creator.key(), amount);
+
+    emit!(VestedTokensClaimed {
+        creator: ctx.accounts.creator.key(),
+        pool: vesting.pool,
+        amount,
+        total_claimed: vesting.claimed_amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    emit!(PositionUpdated {
+        user: ctx.accounts.creator.key(),
+        pool: vesting.pool,
+        total_tokens: position.total_tokens,
+        total_sol: position.total_sol,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ClaimVested<'info> {
+    #[account(
+        mut,
+        seeds = [CreatorVesting::SEED_PREFIX.as_bytes(), creator_vesting.pool.as_ref()],
+        bump = creator_vesting.bump,
+        has_one = creator,
+        has_one = mint,
+    )]
+    pub creator_vesting: Box<Account<'info, CreatorVesting>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = creator_vesting,
+    )]
+    pub vesting_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// Creator's position in the pool, credited with the claimed tokens
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = UserPosition::ACCOUNT_SIZE,
+        seeds = [UserPosition::SEED_PREFIX.as_bytes(), creator_vesting.pool.as_ref(), creator.key().as_ref()],
+        bump,
+    )]
+    pub creator_position: Box<Account<'info, UserPosition>>,
+
+    pub mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        init_if_needed,
+        payer = creator,
+        associated_token::mint = mint,
+        associated_token::authority = creator,
+    )]
+    pub creator_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
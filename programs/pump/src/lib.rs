@@ -1,3 +1,6 @@
+// The CPI wrappers generated for `swap` and `launch` take every instruction argument
+#![cfg_attr(feature = "cpi", allow(clippy::too_many_arguments))]
+
 use anchor_lang::prelude::*;
 
 pub mod consts;
@@ -7,6 +10,9 @@ pub mod state;
 pub mod utils;
 
 use crate::instructions::*;
+use crate::state::{
+    ConfigChange, CreatorRules, FairLaunchParams, LaunchProtectionParams, Presale, VestingParams,
+};
 
 declare_id!("6kmXSnjP83nkiJZNWpM4mmRU8q6nQamLSE8fxGvoTPeS");
 
@@ -52,12 +58,117 @@ pub mod pump {
     /// # Arguments
     /// * `amount` - Amount to swap (tokens if selling, SOL if buying)
     /// * `style` - 1 for SELL (tokens -> SOL), 2 for BUY (SOL -> tokens)
+    /// * `min_amount_out` - Minimum tokens (buy) or SOL before tax (sell) to receive
+    /// * `close_empty_position` - On a sell, close the `UserPosition` and refund its rent if fully exited
+    /// * `deadline_unix_ts` - Optional unix timestamp after which the swap is rejected
+    /// * `max_slot` - Optional last slot in which the swap may execute
+    /// * `max_price_impact_bps` - Optional cap on the move of the effective-reserve spot price
+    /// * `allow_partial_fill` - On a sell larger than the real SOL reserve can pay, sell only
+    ///   the part that fits (unsold tokens stay with the user) instead of failing
     /// 
     /// # PaperHandBitchTax
     /// When selling at a loss (SOL received < cost basis), a 50% tax is applied
     /// to the SOL proceeds and sent to the treasury vault.
-    pub fn swap(ctx: Context<Swap>, amount: u64, style: u64, min_amount_out: u64) -> Result<()> {
-        instructions::swap(ctx, amount, style, min_amount_out)
+    ///
+    /// # CPI
+    /// Returns the filled amounts as a `SwapResult` via return data. `user` may be a PDA
+    /// signing through CPI and `user_token_account_one` any token account it owns.
+    ///
+    /// # Referrals
+    /// When the optional `referrer` account is passed, `referral_fee_bps` of the trading
+    /// fee is paid to it instead of staying in the pool.
+    ///
+    /// # Quote assets
+    /// On a pool quoted in an SPL token, `amount` and `min_amount_out` are in that token and
+    /// the pool, user and treasury quote token accounts are required. Referrals are only
+    /// available on SOL-quoted pools.
+    ///
+    /// # Wrapped SOL
+    /// On SOL-quoted pools, passing `user_wsol_account` pays a buy from wSOL (with
+    /// `wsol_temp_account` and `native_mint`, used to unwrap it) or pays sell proceeds into
+    /// it as wSOL, with no separate sync_native or close-account instructions.
+    #[allow(clippy::too_many_arguments)]
+    pub fn swap(
+        ctx: Context<Swap>,
+        amount: u64,
+        style: u64,
+        min_amount_out: u64,
+        close_empty_position: bool,
+        deadline_unix_ts: Option<i64>,
+        max_slot: Option<u64>,
+        max_price_impact_bps: Option<u16>,
+        allow_partial_fill: bool,
+    ) -> Result<SwapResult> {
+        instructions::swap(
+            ctx,
+            amount,
+            style,
+            min_amount_out,
+            close_empty_position,
+            deadline_unix_ts,
+            max_slot,
+            max_price_impact_bps,
+            allow_partial_fill,
+        )
+    }
+
+    /// Sell token A and buy token B with the proceeds, atomically
+    ///
+    /// # Arguments
+    /// * `amount_in` - Amount of token A to sell
+    /// * `min_amount_out` - Minimum amount of token B to receive
+    /// * `deadline_unix_ts` - Optional unix timestamp after which the swap is rejected
+    /// * `max_slot` - Optional slot after which the swap is rejected
+    /// * `max_price_impact_bps` - Optional maximum price impact of each leg, in bps
+    /// * `allow_partial_fill` - Sell only the part of `amount_in` pool A's real SOL covers
+    ///   instead of failing; `min_amount_out` then scales pro rata
+    ///
+    /// The PaperHand tax applies to the A leg; the SOL left after it buys token B and
+    /// becomes that position's cost basis. Returns a `SwapResult` via return data.
+    #[allow(clippy::too_many_arguments)]
+    pub fn swap_token_to_token(
+        ctx: Context<SwapTokenToToken>,
+        amount_in: u64,
+        min_amount_out: u64,
+        deadline_unix_ts: Option<i64>,
+        max_slot: Option<u64>,
+        max_price_impact_bps: Option<u16>,
+        allow_partial_fill: bool,
+    ) -> Result<SwapResult> {
+        instructions::swap_token_to_token(
+            ctx,
+            amount_in,
+            min_amount_out,
+            deadline_unix_ts,
+            max_slot,
+            max_price_impact_bps,
+            allow_partial_fill,
+        )
+    }
+
+    /// Preview a buy of `sol_in` lamports by `buyer`, failing as `swap` would on the trading
+    /// gates, pauses and launch protection limits. Read-only; returns a `BuyQuote` via return data
+    pub fn quote_buy(ctx: Context<QuotePool>, sol_in: u64) -> Result<BuyQuote> {
+        instructions::quote_buy(ctx, sol_in)
+    }
+
+    /// Preview a sell of `tokens_in` by `seller`, including the PaperHand tax their
+    /// position would incur, failing as `swap` would on the trading gates, pauses and the
+    /// creator's sell lock.
+    /// Read-only; returns a `SellQuote` via return data
+    pub fn quote_sell(ctx: Context<QuoteSell>, tokens_in: u64) -> Result<SellQuote> {
+        instructions::quote_sell(ctx, tokens_in)
+    }
+
+    /// Snapshot of a pool's reserves, price and trading state. Read-only; returns a
+    /// `PoolState` via return data
+    pub fn get_pool_state(ctx: Context<GetPoolState>) -> Result<PoolState> {
+        instructions::get_pool_state(ctx)
+    }
+
+    /// Close an empty `UserPosition` and return its rent to the owner
+    pub fn close_position(ctx: Context<ClosePosition>) -> Result<()> {
+        instructions::close_position(ctx)
     }
 
 // function removed
@@ -65,15 +176,34 @@ pub mod pump {
     /// Launch a new token with Paper Hand Tax enabled
     /// 
     /// Creates a new SPL token, sets Metaplex metadata, initializes the bonding
-    /// curve pool, and mints the initial supply.
+    /// curve pool, and mints the initial supply. Charges the configured launch fee
+    /// to the `treasury` unless `launch_fee_waiver` is provided.
+    ///
+    /// `mint` is a fresh client-generated keypair that must sign; `launch_record` is derived
+    /// from the uppercased symbol and the scope of the configured `SymbolPolicy`.
     /// 
     /// # Arguments
     /// * `name` - Token name (max 32 chars)
     /// * `symbol` - Token symbol (max 10 chars)
     /// * `uri` - Metadata URI (max 200 chars)
-    /// * `decimals` - Token decimals (typically 6 or 9)
-    /// * `initial_supply` - Total supply to mint
-    /// * `initial_sol_reserve` - Initial SOL for bonding curve
+    /// * `decimals` - Token decimals (6 or 9)
+    /// * `initial_supply` - Total supply to mint, in whole tokens (scaled by 10^decimals);
+    ///   the resulting starting price must be within the configured bounds
+    /// * `initial_sol_reserve` - Initial real reserve of the curve, in lamports or, for a pool
+    ///   quoted in an SPL token (pass `quote_asset`, `quote_mint` and the quote token
+    ///   accounts), in base units of that token
+    /// * `initial_buy_sol` - Optional creator buy executed atomically on the new curve
+    ///   (requires `creator_position` and `creator_token_account`)
+    /// * `launch_protection` - Optional anti-sniper window with per-wallet buy caps and
+    ///   a max price impact, bounded by the configuration
+    /// * `presale` - Optional Merkle-allowlisted presale; public `swap` opens at `presale.ends_at`
+    /// * `trading_opens_at` - Optional unix timestamp before which `swap` is rejected
+    /// * `fair_launch` - Optional batch auction: SOL committed until `commit_ends_at` is
+    ///   cleared at one price by `settle_launch` (not combinable with presale or dev buy)
+    /// * `vesting` - Optional creator allocation (bounded share of supply) locked in a vesting
+    ///   escrow with cliff and linear unlock (requires `creator_vesting` and `vesting_token_account`)
+    /// * `creator_rules` - Optional sell lock and higher PaperHand tax rate for the creator's own sells
+    #[allow(clippy::too_many_arguments)]
     pub fn launch(
         ctx: Context<Launch>,
         name: String,
@@ -82,16 +212,175 @@ pub mod pump {
         decimals: u8,
         initial_supply: u64,
         initial_sol_reserve: u64,
+        initial_buy_sol: Option<u64>,
+        launch_protection: Option<LaunchProtectionParams>,
+        presale: Option<Presale>,
+        trading_opens_at: Option<i64>,
+        fair_launch: Option<FairLaunchParams>,
+        vesting: Option<VestingParams>,
+        creator_rules: Option<CreatorRules>,
     ) -> Result<()> {
-        instructions::launch(ctx, name, symbol, uri, decimals, initial_supply, initial_sol_reserve)
+        instructions::launch(
+            ctx,
+            name,
+            symbol,
+            uri,
+            decimals,
+            initial_supply,
+            initial_sol_reserve,
+            initial_buy_sol,
+            launch_protection,
+            presale,
+            trading_opens_at,
+            fair_launch,
+            vesting,
+            creator_rules,
+        )
+    }
+
+    /// Register the caller as a referrer that swaps can credit
+    pub fn register_referrer(ctx: Context<RegisterReferrer>) -> Result<()> {
+        instructions::register_referrer(ctx)
+    }
+
+    /// Withdraw the caller's unclaimed referral fees
+    pub fn claim_referral(ctx: Context<ClaimReferral>) -> Result<()> {
+        instructions::claim_referral(ctx)
     }
 
+    /// Allow new pools to be quoted in an SPL token such as USDC (admin only)
+    ///
+    /// # Arguments
+    /// * `default_virtual_reserve` - Virtual quote reserve of new pools, in quote base units
+    /// * `min_start_price` / `max_start_price` - Start price bounds, in quote base units per
+    ///   whole token scaled by `PRICE_SCALE`
+    pub fn register_quote_asset(
+        ctx: Context<RegisterQuoteAsset>,
+        default_virtual_reserve: u64,
+        min_start_price: u64,
+        max_start_price: u64,
+    ) -> Result<()> {
+        instructions::register_quote_asset(
+            ctx,
+            default_virtual_reserve,
+            min_start_price,
+            max_start_price,
+        )
+    }
+
+    /// Stop new launches in an SPL quote asset (admin only); existing pools keep trading
+    pub fn unregister_quote_asset(ctx: Context<UnregisterQuoteAsset>) -> Result<()> {
+        instructions::unregister_quote_asset(ctx)
+    }
+
+    /// Exempt a creator from the launch fee (admin only)
+    pub fn grant_launch_fee_waiver(ctx: Context<GrantLaunchFeeWaiver>, creator: Pubkey) -> Result<()> {
+        instructions::grant_launch_fee_waiver(ctx, creator)
+    }
+
+    /// Remove a creator's launch fee waiver (admin only)
+    pub fn revoke_launch_fee_waiver(ctx: Context<RevokeLaunchFeeWaiver>) -> Result<()> {
+        instructions::revoke_launch_fee_waiver(ctx)
+    }
+
+    /// Replace the token's name, symbol and URI (pool creator only, same limits as `launch`)
+    pub fn update_token_metadata(
+        ctx: Context<UpdateTokenMetadata>,
+        name: String,
+        symbol: String,
+        uri: String,
+    ) -> Result<()> {
+        instructions::update_token_metadata(ctx, name, symbol, uri)
+    }
+
+    /// Make the token metadata immutable (pool creator only, irreversible)
+    pub fn lock_metadata(ctx: Context<UpdateTokenMetadata>) -> Result<()> {
+        instructions::lock_metadata(ctx)
+    }
+
+    /// Claim the unlocked part of the creator's vesting allocation
+    pub fn claim_vested(ctx: Context<ClaimVested>) -> Result<()> {
+        instructions::claim_vested(ctx)
+    }
+
+    /// Buy during a pool's allowlisted presale
+    ///
+    /// # Arguments
+    /// * `amount` - SOL to spend
+    /// * `min_amount_out` - Minimum tokens to receive
+    /// * `allocation` - The caller's total presale allocation in lamports (part of the leaf)
+    /// * `proof` - Merkle proof of keccak256(user || allocation) against the pool's root
+    pub fn presale_buy(
+        ctx: Context<PresaleBuy>,
+        amount: u64,
+        min_amount_out: u64,
+        allocation: u64,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::presale_buy(ctx, amount, min_amount_out, allocation, proof)
+    }
+
+    /// Commit SOL to a pool's fair-launch auction during its commitment window
+    ///
+    /// # Arguments
+    /// * `amount` - SOL to commit; any part not used at the clearing price is refunded
+    pub fn commit_to_fair_launch(ctx: Context<CommitToFairLaunch>, amount: u64) -> Result<()> {
+        instructions::commit_to_fair_launch(ctx, amount)
+    }
+
+    /// Clear a fair launch at a uniform price and settle the commitments passed in
+    /// `remaining_accounts` as `[commitment, user_position, user_token_account, owner]`
+    /// groups. Permissionless; call until every commitment is settled.
+    pub fn settle_launch<'info>(ctx: Context<'_, '_, 'info, 'info, SettleLaunch<'info>>) -> Result<()> {
+        instructions::settle_launch(ctx)
+    }
+
+    /// Pause trading globally, or for one pool when the `pool` account is provided
+    ///
+    /// # Arguments
+    /// * `allow_sells` - Keep sells open (wind-down) while blocking buys, launches and liquidity adds
+    pub fn pause(ctx: Context<SetPauseState>, allow_sells: bool) -> Result<()> {
+        instructions::pause(ctx, allow_sells)
+    }
+
+    /// Resume trading globally, or for one pool when the `pool` account is provided
+    pub fn unpause(ctx: Context<SetPauseState>) -> Result<()> {
+        instructions::unpause(ctx)
+    }
+
+    /// Reallocate the configuration account to the current layout (admin only)
+    pub fn migrate_config(ctx: Context<MigrateConfig>) -> Result<()> {
+        instructions::migrate_config(ctx)
+    }
+
+    /// Reallocate a pool account to the current layout (payer covers the extra rent)
+    pub fn migrate_pool(ctx: Context<MigratePool>) -> Result<()> {
+        instructions::migrate_pool(ctx)
+    }
+
+    /// Reallocate a user position to the current layout (payer covers the extra rent)
+    pub fn migrate_position(ctx: Context<MigratePosition>) -> Result<()> {
+        instructions::migrate_position(ctx)
+    }
+
+    /// Queue a configuration change behind the configuration timelock
+    ///
+    /// # Arguments
+    /// * `change` - Fields to update (`None` leaves a field unchanged)
     pub fn update_configuration(
         ctx: Context<UpdateCurveConfiguration>,
-        new_fees: Option<u16>,
-        new_treasury: Option<Pubkey>,
-        new_paperhand_tax_bps: Option<u16>,
+        change: ConfigChange,
     ) -> Result<()> {
-        instructions::update_configuration(ctx, new_fees, new_treasury, new_paperhand_tax_bps)
+        instructions::update_configuration(ctx, change)
+    }
+
+    /// Apply a queued configuration change once its timelock has expired
+    pub fn apply_configuration_update(ctx: Context<ApplyConfigurationUpdate>) -> Result<()> {
+        instructions::apply_configuration_update(ctx)
+    }
+
+    /// Cancel a queued configuration change (admin only)
+    pub fn cancel_configuration_update(ctx: Context<CancelConfigurationUpdate>) -> Result<()> {
+        instructions::cancel_configuration_update(ctx)
     }
 }
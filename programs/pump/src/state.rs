@@ -1,3 +1,4 @@
+use crate::consts::*;
 use crate::errors::CustomError;
 // Imports removed
 
@@ -9,6 +10,7 @@ use std::cmp;
 
 
 #[account]
+#[derive(InitSpace)]
 pub struct CurveConfiguration {
     pub fees: u16,
     /// Treasury wallet that receives the PaperHand tax
@@ -21,15 +23,45 @@ pub struct CurveConfiguration {
     /// This creates virtual liquidity for better price curves
     /// 50 SOL = 50_000_000_000 lamports
     pub default_virtual_sol: u64,
+    /// Delay (in seconds) a queued configuration change must wait before it can be applied
+    pub config_timelock_secs: i64,
+    /// Authority allowed to pause and unpause trading (the admin can always do so too)
+    pub pauser: Pubkey,
+    /// Global kill switch applied to every pool
+    pub pause_state: PauseState,
+    /// Layout version, see `migrate_config`
+    pub version: u8,
+    /// Longest anti-sniper launch window a creator can configure (0 disables launch protection)
+    pub max_protection_window_secs: i64,
+    /// Lowest per-wallet SOL cap a creator can configure for the launch window
+    pub min_protection_buy_sol: u64,
+    /// Largest share of supply (in basis points) a creator can reserve for vesting (0 disables it)
+    pub max_creator_allocation_bps: u16,
+    /// Bounds for a new curve's starting price, in lamports per whole token scaled by `PRICE_SCALE`
+    pub min_start_price: u64,
+    pub max_start_price: u64,
+    /// Fee charged to creators by `launch`, paid to the treasury (0 disables it)
+    pub launch_fee_lamports: u64,
+    /// Which launches may share a token symbol, enforced through `LaunchRecord`
+    pub symbol_policy: SymbolPolicy,
+    /// Share of the trading fee (in basis points) paid to a swap's referrer (0 disables referrals)
+    pub referral_fee_bps: u16,
+    /// Zeroed space for future fields; new fields are carved out of it so the account size stays fixed
+    pub reserved: [u8; 211],
 }
 
 impl CurveConfiguration {
     pub const SEED: &'static str = "CurveConfiguration";
     pub const TREASURY_VAULT_SEED: &'static str = "treasury_vault";
 
-    // Discriminator (8) + u16 (2) + Pubkey (32) + u16 (2) + Pubkey (32) + u64 (8) + padding (2)
-    // 8 + 2 + 32 + 2 + 32 + 8 + 2 = 86
-    pub const ACCOUNT_SIZE: usize = 8 + 2 + 32 + 2 + 32 + 8 + 2;
+    /// Current layout version
+    pub const VERSION: u8 = 2;
+
+    /// Size of accounts created before versioning (discriminator + fields + 2 bytes padding)
+    pub const LEGACY_ACCOUNT_SIZE: usize = 8 + 2 + 32 + 2 + 32 + 8 + 2;
+
+    // Discriminator (8) + fields
+    pub const ACCOUNT_SIZE: usize = 8 + Self::INIT_SPACE;
 
     pub fn new(fees: u16, treasury: Pubkey, paperhand_tax_bps: u16, admin: Pubkey) -> Self {
         Self { 
@@ -37,14 +69,687 @@ impl CurveConfiguration {
             treasury,
             paperhand_tax_bps,
             admin,
-            default_virtual_sol: 50_000_000_000, // 50 SOL default
+            default_virtual_sol: DEFAULT_VIRTUAL_SOL, // 50 SOL default
+            config_timelock_secs: DEFAULT_CONFIG_TIMELOCK_SECS,
+            pauser: admin,
+            pause_state: PauseState::Active,
+            version: Self::VERSION,
+            max_protection_window_secs: DEFAULT_MAX_PROTECTION_WINDOW_SECS,
+            min_protection_buy_sol: DEFAULT_MIN_PROTECTION_BUY_SOL,
+            max_creator_allocation_bps: DEFAULT_MAX_CREATOR_ALLOCATION_BPS,
+            min_start_price: DEFAULT_MIN_START_PRICE,
+            max_start_price: DEFAULT_MAX_START_PRICE,
+            launch_fee_lamports: 0,
+            symbol_policy: SymbolPolicy::Unrestricted,
+            referral_fee_bps: 0,
+            reserved: [0; 211],
+        }
+    }
+
+    /// Fill fields introduced after the stored version with their defaults
+    pub fn migrate(&mut self) {
+        if self.version < 1 {
+            self.config_timelock_secs = DEFAULT_CONFIG_TIMELOCK_SECS;
+            self.max_protection_window_secs = DEFAULT_MAX_PROTECTION_WINDOW_SECS;
+            self.min_protection_buy_sol = DEFAULT_MIN_PROTECTION_BUY_SOL;
+            self.max_creator_allocation_bps = DEFAULT_MAX_CREATOR_ALLOCATION_BPS;
+            self.pauser = self.admin;
+            self.pause_state = PauseState::Active;
+        }
+        if self.version < 2 {
+            self.min_start_price = DEFAULT_MIN_START_PRICE;
+            self.max_start_price = DEFAULT_MAX_START_PRICE;
+        }
+        self.version = Self::VERSION;
+    }
+}
+
+// New fields must be carved out of `reserved` so existing accounts keep their size.
+// Growing an account requires bumping its VERSION and reallocating it in the migration.
+const _: () = assert!(CurveConfiguration::ACCOUNT_SIZE == 382);
+const _: () = assert!(LiquidityPool::ACCOUNT_SIZE == 363);
+const _: () = assert!(UserPosition::ACCOUNT_SIZE == 154);
+
+/// Creator-supplied anti-sniper settings for `launch`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct LaunchProtectionParams {
+    /// Length of the protection window after launch
+    pub window_secs: i64,
+    /// Maximum cumulative SOL a single wallet can spend buying during the window
+    pub max_buy_sol: u64,
+    /// Maximum cumulative tokens a single wallet can buy during the window
+    pub max_buy_tokens: u64,
+    /// Maximum price impact of a single buy during the window
+    pub max_price_impact_bps: u16,
+}
+
+impl LaunchProtectionParams {
+    /// Validate against the bounds in the configuration
+    pub fn validate(&self, config: &CurveConfiguration) -> Result<()> {
+        require!(
+            self.window_secs > 0 && self.window_secs <= config.max_protection_window_secs,
+            CustomError::InvalidLaunchProtection
+        );
+        require!(
+            self.max_buy_sol >= config.min_protection_buy_sol && self.max_buy_sol > 0,
+            CustomError::InvalidLaunchProtection
+        );
+        require!(self.max_buy_tokens > 0, CustomError::InvalidLaunchProtection);
+        require!(
+            self.max_price_impact_bps > 0 && self.max_price_impact_bps <= 10000,
+            CustomError::InvalidLaunchProtection
+        );
+        Ok(())
+    }
+}
+
+/// Anti-sniper limits applied to buys until `ends_at` (all zero when disabled)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, InitSpace)]
+pub struct LaunchProtection {
+    /// Unix timestamp at which the protection window closes
+    pub ends_at: i64,
+    pub max_buy_sol: u64,
+    pub max_buy_tokens: u64,
+    pub max_price_impact_bps: u16,
+}
+
+impl LaunchProtection {
+    /// Protection starting at `trading_opens_at` for `params.window_secs`
+    pub fn new(params: &LaunchProtectionParams, trading_opens_at: i64) -> Result<Self> {
+        Ok(Self {
+            ends_at: trading_opens_at
+                .checked_add(params.window_secs)
+                .ok_or(CustomError::MathOverflow)?,
+            max_buy_sol: params.max_buy_sol,
+            max_buy_tokens: params.max_buy_tokens,
+            max_price_impact_bps: params.max_price_impact_bps,
+        })
+    }
+
+    pub fn is_active(&self, now: i64) -> bool {
+        now < self.ends_at
+    }
+
+    /// Check a buy against the window limits and record it on the position
+    pub fn enforce_buy(
+        &self,
+        position: &mut UserPosition,
+        sol_in: u64,
+        tokens_out: u64,
+        price_impact_bps: u64,
+    ) -> Result<()> {
+        require!(
+            price_impact_bps <= self.max_price_impact_bps as u64,
+            CustomError::PriceImpactTooHigh
+        );
+
+        let window_sol = position.launch_window_sol
+            .checked_add(sol_in)
+            .ok_or(CustomError::MathOverflow)?;
+        let window_tokens = position.launch_window_tokens
+            .checked_add(tokens_out)
+            .ok_or(CustomError::MathOverflow)?;
+        require!(
+            window_sol <= self.max_buy_sol && window_tokens <= self.max_buy_tokens,
+            CustomError::LaunchBuyCapExceeded
+        );
+
+        position.launch_window_sol = window_sol;
+        position.launch_window_tokens = window_tokens;
+        Ok(())
+    }
+}
+
+/// Merkle-allowlisted presale phase. Until `ends_at` only allowlisted wallets can buy
+/// (through `presale_buy`) and public `swap` is closed. All zero when disabled.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, InitSpace)]
+pub struct Presale {
+    /// Root of the allowlist tree; leaves are keccak256(wallet || allocation as u64 LE)
+    pub merkle_root: [u8; 32],
+    /// Unix timestamp at which the presale ends and public trading opens
+    pub ends_at: i64,
+}
+
+impl Presale {
+    /// The presale closes public trading, sells included, so it must end within
+    /// `MAX_TRADING_START_DELAY_SECS` like a scheduled trading start
+    pub fn validate(&self, now: i64) -> Result<()> {
+        require!(self.merkle_root != [0; 32], CustomError::InvalidPresale);
+        let latest_end = now
+            .checked_add(MAX_TRADING_START_DELAY_SECS)
+            .ok_or(CustomError::MathOverflow)?;
+        require!(self.ends_at > now && self.ends_at <= latest_end, CustomError::InvalidPresale);
+        Ok(())
+    }
+
+    pub fn is_active(&self, now: i64) -> bool {
+        now < self.ends_at
+    }
+}
+
+/// Creator-supplied batch-auction settings for `launch`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct FairLaunchParams {
+    /// Unix timestamp at which the commitment window closes
+    pub commit_ends_at: i64,
+    /// Maximum tokens sold in the auction; excess commitments are refunded pro rata
+    pub max_tokens: u64,
+}
+
+/// Batch-auction opening phase: commitments collected until `commit_ends_at` are all
+/// cleared at one uniform curve price by `settle_launch`. All zero when disabled.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, InitSpace)]
+pub struct FairLaunch {
+    pub commit_ends_at: i64,
+    pub max_tokens: u64,
+    /// Total SOL committed by all participants
+    pub total_committed: u64,
+    /// Tokens bought for the participants at clearing
+    pub tokens_sold: u64,
+    /// SOL of the commitments actually spent at clearing (the rest is refunded)
+    pub sol_used: u64,
+    /// Whether the auction has been cleared; public trading stays closed until it is
+    pub cleared: bool,
+    /// Commitments settled so far, in SOL committed; equals `total_committed` once every
+    /// participant has been credited
+    pub sol_settled: u64,
+}
+
+impl FairLaunch {
+    pub fn new(params: &FairLaunchParams) -> Self {
+        Self {
+            commit_ends_at: params.commit_ends_at,
+            max_tokens: params.max_tokens,
+            ..Self::default()
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.commit_ends_at != 0
+    }
+
+    pub fn is_committing(&self, now: i64) -> bool {
+        now < self.commit_ends_at
+    }
+
+    /// Public trading is blocked until an enabled auction has been cleared
+    pub fn blocks_trading(&self) -> bool {
+        self.is_enabled() && !self.cleared
+    }
+
+    /// Whether an enabled auction still has commitments waiting for `settle_launch`
+    pub fn has_unsettled_commitments(&self) -> bool {
+        self.is_enabled() && self.sol_settled < self.total_committed
+    }
+
+    /// Share of the clearing allotted to a commitment: (tokens, sol_spent, refund).
+    /// Tokens round down and SOL spent rounds up so the pool never pays out more than it cleared.
+    pub fn allocation_for(&self, committed: u64) -> Result<(u64, u64, u64)> {
+        let total = self.total_committed as u128;
+        require!(total > 0, CustomError::MathOverflow);
+
+        let tokens = (self.tokens_sold as u128)
+            .checked_mul(committed as u128)
+            .ok_or(CustomError::MathOverflow)?
+            / total;
+        let sol_spent = (self.sol_used as u128)
+            .checked_mul(committed as u128)
+            .ok_or(CustomError::MathOverflow)?
+            .div_ceil(total);
+        let refund = (committed as u128)
+            .checked_sub(sol_spent)
+            .ok_or(CustomError::MathOverflow)?;
+
+        Ok((tokens as u64, sol_spent as u64, refund as u64))
+    }
+}
+
+/// A participant's SOL escrowed for a fair-launch auction, closed on settlement
+#[account]
+#[derive(InitSpace)]
+pub struct LaunchCommitment {
+    pub pool: Pubkey,
+    pub owner: Pubkey,
+    /// SOL committed (held in the global vault until settlement)
+    pub sol_committed: u64,
+    pub bump: u8,
+}
+
+impl LaunchCommitment {
+    pub const SEED_PREFIX: &'static str = "commitment";
+
+    // Discriminator (8) + fields
+    pub const ACCOUNT_SIZE: usize = 8 + Self::INIT_SPACE;
+}
+
+/// Creator-supplied vesting schedule for a reserved share of supply in `launch`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct VestingParams {
+    /// Share of the initial supply reserved for the creator, in basis points
+    pub allocation_bps: u16,
+    /// Delay after launch before anything can be claimed
+    pub cliff_secs: i64,
+    /// Total length of the linear unlock, starting at launch
+    pub duration_secs: i64,
+}
+
+impl VestingParams {
+    /// Validate against the bounds in the configuration
+    pub fn validate(&self, config: &CurveConfiguration) -> Result<()> {
+        require!(
+            self.allocation_bps > 0 && self.allocation_bps <= config.max_creator_allocation_bps,
+            CustomError::InvalidVesting
+        );
+        require!(
+            self.duration_secs > 0 && self.duration_secs <= MAX_VESTING_DURATION_SECS,
+            CustomError::InvalidVesting
+        );
+        require!(
+            (0..=self.duration_secs).contains(&self.cliff_secs),
+            CustomError::InvalidVesting
+        );
+        Ok(())
+    }
+
+    /// Tokens reserved out of `initial_supply`
+    pub fn allocation(&self, initial_supply: u64) -> Result<u64> {
+        let amount = (initial_supply as u128)
+            .checked_mul(self.allocation_bps as u128)
+            .ok_or(CustomError::MathOverflow)?
+            / 10000;
+        require!(amount > 0, CustomError::InvalidVesting);
+        Ok(amount as u64)
+    }
+}
+
+/// Escrow of a creator's reserved allocation. The tokens sit in the associated token
+/// account of this PDA, outside the curve reserves, and unlock linearly from
+/// `start_ts` to `end_ts` with nothing claimable before `cliff_ts`.
+#[account]
+#[derive(InitSpace)]
+pub struct CreatorVesting {
+    pub pool: Pubkey,
+    pub mint: Pubkey,
+    pub creator: Pubkey,
+    /// Tokens locked at launch
+    pub total_amount: u64,
+    /// Tokens already claimed
+    pub claimed_amount: u64,
+    pub start_ts: i64,
+    pub cliff_ts: i64,
+    pub end_ts: i64,
+    /// Curve spot price at launch, the cost basis of claimed tokens
+    pub start_price: u64,
+    pub bump: u8,
+}
+
+impl CreatorVesting {
+    pub const SEED_PREFIX: &'static str = "vesting";
+
+    // Discriminator (8) + fields
+    pub const ACCOUNT_SIZE: usize = 8 + Self::INIT_SPACE;
+
+    /// Tokens unlocked at `now`, claimed or not
+    pub fn vested_amount(&self, now: i64) -> Result<u64> {
+        if now < self.cliff_ts {
+            return Ok(0);
+        }
+        if now >= self.end_ts {
+            return Ok(self.total_amount);
+        }
+
+        let elapsed = (now - self.start_ts) as u128;
+        let duration = (self.end_ts - self.start_ts) as u128;
+        let vested = (self.total_amount as u128)
+            .checked_mul(elapsed)
+            .ok_or(CustomError::MathOverflow)?
+            / duration;
+        Ok(vested as u64)
+    }
+
+    /// Tokens that can be claimed at `now`
+    pub fn claimable_amount(&self, now: i64) -> Result<u64> {
+        self.vested_amount(now)?
+            .checked_sub(self.claimed_amount)
+            .ok_or(CustomError::MathOverflow.into())
+    }
+
+    /// Cost basis of `amount` claimed tokens, valued at the launch price.
+    /// A zero basis would drag the creator's average cost down and exempt their
+    /// losing sells from the PaperHand tax.
+    pub fn cost_basis_for(&self, amount: u64, decimals: u8) -> Result<u64> {
+        let cost = (amount as u128)
+            .checked_mul(self.start_price as u128)
+            .ok_or(CustomError::MathOverflow)?
+            / (10u128.pow(decimals as u32) * PRICE_SCALE);
+        u64::try_from(cost).map_err(|_| CustomError::MathOverflow.into())
+    }
+}
+
+/// Stricter sell rules the creator opts into at launch. All zero when disabled.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, InitSpace)]
+pub struct CreatorRules {
+    /// Unix timestamp before which the creator cannot sell
+    pub sell_locked_until: i64,
+    /// PaperHand tax rate applied to the creator's losing sells instead of the global rate
+    pub paperhand_tax_bps: u16,
+}
+
+impl CreatorRules {
+    /// The rules can only make things stricter than for everyone else
+    pub fn validate(&self, config: &CurveConfiguration, now: i64) -> Result<()> {
+        if self.sell_locked_until != 0 {
+            require!(
+                self.sell_locked_until > now
+                    && self.sell_locked_until - now <= MAX_CREATOR_SELL_LOCK_SECS,
+                CustomError::InvalidCreatorRules
+            );
+        }
+        if self.paperhand_tax_bps != 0 {
+            require!(
+                self.paperhand_tax_bps > config.paperhand_tax_bps && self.paperhand_tax_bps <= 10000,
+                CustomError::InvalidCreatorRules
+            );
+        }
+        Ok(())
+    }
+
+    pub fn ensure_sells_unlocked(&self, now: i64) -> Result<()> {
+        require!(now >= self.sell_locked_until, CustomError::CreatorSellLocked);
+        Ok(())
+    }
+
+    /// Tax rate for the creator: never lower than the global rate, even if the admin raises it later
+    pub fn paperhand_tax_bps(&self, global_tax_bps: u16) -> u16 {
+        self.paperhand_tax_bps.max(global_tax_bps)
+    }
+}
+
+/// Marks a creator as exempt from the launch fee; granted and revoked by the admin
+#[account]
+#[derive(InitSpace)]
+pub struct LaunchFeeWaiver {
+    pub creator: Pubkey,
+    pub bump: u8,
+}
+
+impl LaunchFeeWaiver {
+    pub const SEED_PREFIX: &'static str = "launch_fee_waiver";
+
+    // Discriminator (8) + fields
+    pub const ACCOUNT_SIZE: usize = 8 + Self::INIT_SPACE;
+}
+
+/// SPL token the admin allows as a curve's quote asset (e.g. USDC), with the curve
+/// parameters that replace the lamport-denominated configuration defaults
+#[account]
+#[derive(InitSpace)]
+pub struct QuoteAsset {
+    pub mint: Pubkey,
+    /// Virtual quote reserve of new pools, in the quote token's base units
+    pub default_virtual_reserve: u64,
+    /// Start price bounds, in quote base units per whole token scaled by `PRICE_SCALE`
+    pub min_start_price: u64,
+    pub max_start_price: u64,
+    pub bump: u8,
+}
+
+impl QuoteAsset {
+    pub const SEED_PREFIX: &'static str = "quote_asset";
+
+    // Discriminator (8) + fields
+    pub const ACCOUNT_SIZE: usize = 8 + Self::INIT_SPACE;
+}
+
+/// Uniqueness rule for token symbols across launches
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq, InitSpace)]
+pub enum SymbolPolicy {
+    /// Any number of launches can use the same symbol
+    #[default]
+    Unrestricted,
+    /// A creator can use a symbol once; other creators can reuse it
+    UniquePerCreator,
+    /// A symbol can only be launched once across the protocol
+    GloballyUnique,
+}
+
+impl SymbolPolicy {
+    /// Scope of the `LaunchRecord` seeds: launches sharing a symbol and a scope collide
+    pub fn scope(self, creator: &Pubkey, mint: &Pubkey) -> Pubkey {
+        match self {
+            SymbolPolicy::Unrestricted => *mint,
+            SymbolPolicy::UniquePerCreator => *creator,
+            SymbolPolicy::GloballyUnique => Pubkey::default(),
+        }
+    }
+}
+
+/// Proof that a symbol was launched within a uniqueness scope (see `SymbolPolicy`).
+/// Its address is derived from the uppercased symbol and the scope, so a second
+/// launch in the same scope fails to create it.
+#[account]
+#[derive(InitSpace)]
+pub struct LaunchRecord {
+    pub mint: Pubkey,
+    pub creator: Pubkey,
+    #[max_len(10)]
+    pub symbol: String,
+    pub launched_at: i64,
+    pub bump: u8,
+}
+
+impl LaunchRecord {
+    pub const SEED_PREFIX: &'static str = "launch_record";
+
+    // Discriminator (8) + fields
+    pub const ACCOUNT_SIZE: usize = 8 + Self::INIT_SPACE;
+}
+
+/// A referrer's earnings from swaps routed through them. Earned lamports are held
+/// in this account until claimed with `claim_referral`.
+#[account]
+#[derive(InitSpace)]
+pub struct Referrer {
+    pub owner: Pubkey,
+    /// Cumulative referral fees earned
+    pub total_earned: u64,
+    /// Cumulative referral fees claimed
+    pub total_claimed: u64,
+    pub bump: u8,
+}
+
+impl Referrer {
+    pub const SEED_PREFIX: &'static str = "referrer";
+
+    // Discriminator (8) + fields
+    pub const ACCOUNT_SIZE: usize = 8 + Self::INIT_SPACE;
+
+    pub fn record_fee(&mut self, fee: u64) -> Result<()> {
+        self.total_earned = self.total_earned
+            .checked_add(fee)
+            .ok_or(CustomError::MathOverflow)?;
+        Ok(())
+    }
+
+    pub fn unclaimed(&self) -> u64 {
+        self.total_earned.saturating_sub(self.total_claimed)
+    }
+}
+
+/// Emergency pause state, used both globally and per pool
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq, InitSpace)]
+pub enum PauseState {
+    /// Normal operation
+    #[default]
+    Active,
+    /// Wind-down: sells are allowed, buys and launches are blocked
+    SellOnly,
+    /// Everything is blocked
+    Paused,
+}
+
+impl PauseState {
+    pub fn ensure_buys_allowed(self) -> Result<()> {
+        match self {
+            PauseState::Active => Ok(()),
+            PauseState::SellOnly => err!(CustomError::BuysPaused),
+            PauseState::Paused => err!(CustomError::TradingPaused),
+        }
+    }
+
+    pub fn ensure_sells_allowed(self) -> Result<()> {
+        match self {
+            PauseState::Paused => err!(CustomError::TradingPaused),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// A set of configuration updates; `None` fields are left unchanged
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, Debug, InitSpace)]
+pub struct ConfigChange {
+    pub fees: Option<u16>,
+    pub treasury: Option<Pubkey>,
+    pub paperhand_tax_bps: Option<u16>,
+    pub config_timelock_secs: Option<i64>,
+    pub pauser: Option<Pubkey>,
+    pub admin: Option<Pubkey>,
+    pub default_virtual_sol: Option<u64>,
+    pub max_protection_window_secs: Option<i64>,
+    pub min_protection_buy_sol: Option<u64>,
+    pub max_creator_allocation_bps: Option<u16>,
+    pub min_start_price: Option<u64>,
+    pub max_start_price: Option<u64>,
+    pub launch_fee_lamports: Option<u64>,
+    pub symbol_policy: Option<SymbolPolicy>,
+    pub referral_fee_bps: Option<u16>,
+}
+
+impl ConfigChange {
+    // Option tag (1) + value for every field
+    pub const MAX_SIZE: usize = Self::INIT_SPACE;
+
+    pub fn is_empty(&self) -> bool {
+        self.fees.is_none()
+            && self.treasury.is_none()
+            && self.paperhand_tax_bps.is_none()
+            && self.config_timelock_secs.is_none()
+            && self.pauser.is_none()
+            && self.admin.is_none()
+            && self.default_virtual_sol.is_none()
+            && self.max_protection_window_secs.is_none()
+            && self.min_protection_buy_sol.is_none()
+            && self.max_creator_allocation_bps.is_none()
+            && self.min_start_price.is_none()
+            && self.max_start_price.is_none()
+            && self.launch_fee_lamports.is_none()
+            && self.symbol_policy.is_none()
+            && self.referral_fee_bps.is_none()
+    }
+
+    /// Validate every field that is being changed
+    pub fn validate(&self) -> Result<()> {
+        require!(!self.is_empty(), CustomError::EmptyConfigChange);
+
+        if let Some(fees) = self.fees {
+            require!(fees <= 10000, CustomError::InvalidFee);
+        }
+
+        if let Some(tax_bps) = self.paperhand_tax_bps {
+            require!(tax_bps <= 10000, CustomError::InvalidTaxBps);
+        }
+
+        if let Some(treasury) = self.treasury {
+            require!(treasury != Pubkey::default(), CustomError::InvalidTreasury);
+        }
+
+        if let Some(delay) = self.config_timelock_secs {
+            require!(
+                (MIN_CONFIG_TIMELOCK_SECS..=MAX_CONFIG_TIMELOCK_SECS).contains(&delay),
+                CustomError::InvalidTimelock
+            );
+        }
+
+        if let Some(pauser) = self.pauser {
+            require!(pauser != Pubkey::default(), CustomError::InvalidAuthority);
+        }
+
+        if let Some(admin) = self.admin {
+            require!(admin != Pubkey::default(), CustomError::InvalidAuthority);
         }
+
+        if let Some(virtual_sol) = self.default_virtual_sol {
+            require!(
+                (MIN_VIRTUAL_SOL..=MAX_VIRTUAL_SOL).contains(&virtual_sol),
+                CustomError::InvalidVirtualSol
+            );
+        }
+
+        if let Some(window) = self.max_protection_window_secs {
+            require!(
+                (0..=MAX_PROTECTION_WINDOW_SECS).contains(&window),
+                CustomError::InvalidLaunchProtection
+            );
+        }
+
+        if let Some(allocation_bps) = self.max_creator_allocation_bps {
+            require!(allocation_bps <= MAX_CREATOR_ALLOCATION_BPS, CustomError::InvalidVesting);
+        }
+
+        if let Some(fee) = self.launch_fee_lamports {
+            require!(fee <= MAX_LAUNCH_FEE_LAMPORTS, CustomError::InvalidLaunchFee);
+        }
+
+        if let Some(referral_bps) = self.referral_fee_bps {
+            require!(referral_bps <= 10000, CustomError::InvalidFee);
+        }
+
+        Ok(())
+    }
+
+    /// Validate the change and the configuration it would produce: a change to one
+    /// start price bound must still leave a usable range with the stored other bound
+    pub fn validate_for(&self, config: &CurveConfiguration) -> Result<()> {
+        self.validate()?;
+
+        let min = self.min_start_price.unwrap_or(config.min_start_price);
+        let max = self.max_start_price.unwrap_or(config.max_start_price);
+        require!(min <= max, CustomError::InvalidPriceBounds);
+
+        Ok(())
     }
 }
 
+/// A configuration change waiting for its timelock to expire.
+/// Only one change can be pending at a time; it must be applied or cancelled
+/// before another one is queued.
+#[account]
+pub struct PendingConfigChange {
+    /// The configuration this change applies to
+    pub config: Pubkey,
+    /// Admin that queued the change (receives the rent back on close)
+    pub proposer: Pubkey,
+    /// The queued updates
+    pub change: ConfigChange,
+    /// Unix timestamp the change was queued at
+    pub queued_at: i64,
+    /// Unix timestamp after which the change can be applied
+    pub eta: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl PendingConfigChange {
+    pub const SEED: &'static str = "pending_config_change";
+
+    // Discriminator (8) + Pubkey (32) + Pubkey (32) + ConfigChange + i64 (8) + i64 (8) + u8 (1)
+    pub const ACCOUNT_SIZE: usize = 8 + 32 + 32 + ConfigChange::MAX_SIZE + 8 + 8 + 1;
+}
+
 /// Tracks a user's cost basis for a specific pool
 /// Used to determine if a sell is at a loss for PaperHandBitchTax
 #[account]
+#[derive(InitSpace)]
 pub struct UserPosition {
     /// The pool this position is for
     pub pool: Pubkey,
@@ -56,13 +761,29 @@ pub struct UserPosition {
     pub total_sol: u64,
     /// PDA bump seed
     pub bump: u8,
+    /// Layout version, see `migrate_position`
+    pub version: u8,
+    /// SOL spent buying during the pool's launch protection window
+    pub launch_window_sol: u64,
+    /// Tokens bought during the pool's launch protection window
+    pub launch_window_tokens: u64,
+    /// SOL spent in the pool's allowlisted presale
+    pub presale_sol: u64,
+    /// Zeroed space for future fields
+    pub reserved: [u8; 40],
 }
 
 impl UserPosition {
     pub const SEED_PREFIX: &'static str = "position";
 
-    // Discriminator (8) + Pubkey (32) + Pubkey (32) + u64 (8) + u64 (8) + u8 (1)
-    pub const ACCOUNT_SIZE: usize = 8 + 32 + 32 + 8 + 8 + 1;
+    /// Current layout version
+    pub const VERSION: u8 = 1;
+
+    /// Size of accounts created before versioning
+    pub const LEGACY_ACCOUNT_SIZE: usize = 8 + 32 + 32 + 8 + 8 + 1;
+
+    // Discriminator (8) + fields
+    pub const ACCOUNT_SIZE: usize = 8 + Self::INIT_SPACE;
 
     pub fn new(pool: Pubkey, owner: Pubkey, bump: u8) -> Self {
         Self {
@@ -71,9 +792,19 @@ impl UserPosition {
             total_tokens: 0,
             total_sol: 0,
             bump,
+            version: Self::VERSION,
+            launch_window_sol: 0,
+            launch_window_tokens: 0,
+            presale_sol: 0,
+            reserved: [0; 40],
         }
     }
 
+    /// Fill fields introduced after the stored version with their defaults
+    pub fn migrate(&mut self) {
+        self.version = Self::VERSION;
+    }
+
     /// Calculate cost basis for a given token amount using u128 for overflow safety
     /// Returns the proportional SOL cost for the tokens being sold
     pub fn calculate_cost_basis_for_sale(&self, token_amount: u64) -> Result<u64> {
@@ -139,40 +870,362 @@ impl LiquidityProvider {
 }
 
 #[account]
+#[derive(InitSpace)]
 pub struct LiquidityPool {
     pub token_one: Pubkey, // Public key of the first token in the liquidity pool
-    pub token_two: Pubkey, // Public key of the second token in the pool
+    pub token_two: Pubkey, // Quote mint, or the System Program ID for native SOL
     pub total_supply: u64, // Total supply of liquidity tokens
     pub reserve_one: u64,  // Reserve amount of token_one in the pool
-    pub reserve_two: u64,  // Reserve amount of token_two (SOL) in the pool
+    pub reserve_two: u64,  // Reserve amount of token_two (lamports or quote base units) in the pool
     pub virtual_sol_reserve: u64, // Virtual SOL reserve for price calculation
     pub bump: u8,          // Nonce for the program-derived address
+    pub pause_state: PauseState, // Per-pool kill switch
+    pub version: u8,       // Layout version, see `migrate_pool`
+    pub launch_protection: LaunchProtection, // Anti-sniper limits right after launch
+    pub presale: Presale,  // Allowlisted presale phase before public trading
+    pub trading_opens_at: i64, // Unix timestamp before which `swap` is rejected (0 = immediately)
+    pub fair_launch: FairLaunch, // Batch-auction opening phase
+    pub creator: Pubkey,   // Wallet that launched the pool (default for pools created before it was recorded)
+    pub creator_rules: CreatorRules, // Stricter sell rules applied to the creator
+    pub reserved: [u8; 91], // Zeroed space for future fields
 }
 
 impl LiquidityPool {
     pub const POOL_SEED_PREFIX: &'static str = "liquidity_pool";
 
-    // Discriminator (8) + Pubkey (32) + Pubkey (32) + totalsupply (8)
-    // + reserve one (8) + reserve two (8) + virtual_sol_reserve (8) + Bump (1)
-    pub const ACCOUNT_SIZE: usize = 8 + 32 + 32 + 8 + 8 + 8 + 8 + 1;
+    // Current layout version
+    pub const VERSION: u8 = 1;
+
+    // Size of accounts created before versioning: Discriminator (8) + Pubkey (32) + Pubkey (32)
+    // + totalsupply (8) + reserve one (8) + reserve two (8) + virtual_sol_reserve (8) + Bump (1)
+    pub const LEGACY_ACCOUNT_SIZE: usize = 8 + 32 + 32 + 8 + 8 + 8 + 8 + 1;
+
+    // Discriminator (8) + fields
+    pub const ACCOUNT_SIZE: usize = 8 + Self::INIT_SPACE;
 
     // Constructor to initialize a LiquidityPool with two tokens and a bump for the PDA
     pub fn new(token_one: Pubkey, bump: u8, virtual_sol: u64) -> Self {
         Self {
-            token_one: token_one,
-            token_two: token_one,
+            token_one,
+            token_two: system_program::ID,
             total_supply: 0_u64,
             reserve_one: 0_u64,
             reserve_two: 0_u64,
             virtual_sol_reserve: virtual_sol,
-            bump: bump,
+            bump,
+            pause_state: PauseState::Active,
+            version: Self::VERSION,
+            launch_protection: LaunchProtection::default(),
+            presale: Presale::default(),
+            trading_opens_at: 0,
+            fair_launch: FairLaunch::default(),
+            creator: Pubkey::default(),
+            creator_rules: CreatorRules::default(),
+            reserved: [0; 91],
         }
     }
+
+    // Fill fields introduced after the stored version with their defaults
+    pub fn migrate(&mut self) {
+        if self.version < 1 {
+            self.pause_state = PauseState::Active;
+        }
+        self.version = Self::VERSION;
+    }
     
+    /// SPL quote mint of the pool, `None` when it trades against native SOL
+    /// (pools created by `add_liquidity` used to store their own mint here)
+    pub fn quote_mint(&self) -> Option<Pubkey> {
+        if self.token_two == system_program::ID || self.token_two == self.token_one {
+            None
+        } else {
+            Some(self.token_two)
+        }
+    }
+
+    pub fn is_creator(&self, wallet: &Pubkey) -> bool {
+        self.creator != Pubkey::default() && self.creator == *wallet
+    }
+
+    /// PaperHand tax rate for a seller: the creator may be subject to a higher rate
+    pub fn paperhand_tax_bps_for(&self, seller: &Pubkey, global_tax_bps: u16) -> u16 {
+        if self.is_creator(seller) {
+            self.creator_rules.paperhand_tax_bps(global_tax_bps)
+        } else {
+            global_tax_bps
+        }
+    }
+
+    /// When public `swap` trading opens: after the scheduled start, any presale and
+    /// any fair-launch commitment window
+    pub fn public_trading_opens_at(&self) -> i64 {
+        self.trading_opens_at
+            .max(self.presale.ends_at)
+            .max(self.fair_launch.commit_ends_at)
+    }
+
+    /// Positions hold the per-wallet launch window counters, so they cannot be closed
+    /// (and the counters reset) while the launch protection window is active. Fair launch
+    /// participants' positions must also survive until settlement credits them: an
+    /// unsettled participant holds no tokens yet, but `settle_launch` needs the position.
+    pub fn ensure_positions_closable(&self, now: i64) -> Result<()> {
+        require!(!self.launch_protection.is_active(now), CustomError::PositionCloseLocked);
+        require!(!self.fair_launch.has_unsettled_commitments(), CustomError::PositionCloseLocked);
+        Ok(())
+    }
+
+    /// Reject `swap` trading before the scheduled start, during an allowlisted presale
+    /// and until a fair launch is cleared
+    pub fn ensure_public_trading(&self, now: i64) -> Result<()> {
+        require!(now >= self.trading_opens_at, CustomError::TradingNotOpen);
+        require!(!self.presale.is_active(now), CustomError::PresaleActive);
+        require!(!self.fair_launch.blocks_trading(), CustomError::FairLaunchNotSettled);
+        Ok(())
+    }
+
+    /// Spot price in lamports per whole token, scaled by `PRICE_SCALE`
+    pub fn spot_price(&self, decimals: u8) -> Result<u64> {
+        require!(self.reserve_one > 0, CustomError::MathOverflow);
+        let price = (self.effective_sol_reserve() as u128)
+            .checked_mul(10u128.pow(decimals as u32))
+            .and_then(|v| v.checked_mul(PRICE_SCALE))
+            .ok_or(CustomError::MathOverflow)?
+            / self.reserve_one as u128;
+        u64::try_from(price).map_err(|_| CustomError::MathOverflow.into())
+    }
+
     /// Get effective SOL reserve (real + virtual) for price calculations
     pub fn effective_sol_reserve(&self) -> u64 {
         self.reserve_two.saturating_add(self.virtual_sol_reserve)
     }
+
+    /// Compute fee-adjusted amount: amount * (10000 - fees) / 10000
+    pub fn fee_adjusted_amount(amount: u64, fees_bps: u16) -> Result<u64> {
+        let adjusted = (amount as u128)
+            .checked_mul(10000u128.checked_sub(fees_bps as u128).ok_or(CustomError::MathOverflow)?)
+            .ok_or(CustomError::MathOverflow)?
+            .checked_div(10000)
+            .ok_or(CustomError::MathOverflow)?;
+        Ok(adjusted as u64)
+    }
+
+    /// Tokens received for buying with `sol_in` lamports, after trading fees.
+    /// Uses the EFFECTIVE SOL reserve (real + virtual):
+    /// (effective_sol + adjusted_in) * (reserve_one - tokens_out) = reserve_one * effective_sol
+    pub fn quote_buy(&self, sol_in: u64, fees_bps: u16) -> Result<u64> {
+        let adjusted_amt = Self::fee_adjusted_amount(sol_in, fees_bps)? as u128;
+        let reserve_one = self.reserve_one as u128;
+        let effective_sol = self.effective_sol_reserve() as u128;
+
+        let denominator = effective_sol.checked_add(adjusted_amt).ok_or(CustomError::OverflowOrUnderflowOccurred)?;
+        let k = reserve_one.checked_mul(effective_sol).ok_or(CustomError::MathOverflow)?;
+
+        let new_reserve_one = k.checked_div(denominator).ok_or(CustomError::MathOverflow)?;
+        let tokens_out = reserve_one.checked_sub(new_reserve_one).ok_or(CustomError::MathOverflow)?;
+        Ok(tokens_out as u64)
+    }
+
+    /// SOL received (before any PaperHand tax) for selling `tokens_in`, after trading fees.
+    /// Uses the EFFECTIVE SOL reserve (real + virtual):
+    /// (reserve_one + adjusted_in) * (effective_sol - sol_out) = reserve_one * effective_sol
+    pub fn quote_sell(&self, tokens_in: u64, fees_bps: u16) -> Result<u64> {
+        let adjusted_amt = Self::fee_adjusted_amount(tokens_in, fees_bps)? as u128;
+        let reserve_one = self.reserve_one as u128;
+        let effective_sol = self.effective_sol_reserve() as u128;
+
+        let denominator = reserve_one.checked_add(adjusted_amt).ok_or(CustomError::OverflowOrUnderflowOccurred)?;
+        let k = reserve_one.checked_mul(effective_sol).ok_or(CustomError::MathOverflow)?;
+
+        let new_effective_sol = k.checked_div(denominator).ok_or(CustomError::MathOverflow)?;
+        let sol_out = effective_sol.checked_sub(new_effective_sol).ok_or(CustomError::MathOverflow)?;
+        Ok(sol_out as u64)
+    }
+
+    /// Trading fee charged on a buy of `sol_in`, in lamports
+    pub fn buy_fee(sol_in: u64, fees_bps: u16) -> Result<u64> {
+        sol_in
+            .checked_sub(Self::fee_adjusted_amount(sol_in, fees_bps)?)
+            .ok_or(CustomError::MathOverflow.into())
+    }
+
+    /// Trading fee charged on a sell of `tokens_in`, valued in lamports of proceeds forgone
+    pub fn sell_fee(&self, tokens_in: u64, fees_bps: u16) -> Result<u64> {
+        self.quote_sell(tokens_in, 0)?
+            .checked_sub(self.quote_sell(tokens_in, fees_bps)?)
+            .ok_or(CustomError::MathOverflow.into())
+    }
+
+    /// Largest token amount whose sell proceeds (after fees) are covered by the real SOL
+    /// reserve. Pricing uses real + virtual SOL, so without this cap a large sell could
+    /// price above the SOL actually held for the pool.
+    pub fn max_sell_for_real_sol(&self, fees_bps: u16) -> Result<u64> {
+        // sol_out <= reserve_two  <=>  reserve_one + adjusted_in <= k / virtual_sol_reserve
+        if self.virtual_sol_reserve == 0 {
+            return Ok(u64::MAX);
+        }
+        let reserve_one = self.reserve_one as u128;
+        let k = reserve_one
+            .checked_mul(self.effective_sol_reserve() as u128)
+            .ok_or(CustomError::MathOverflow)?;
+        let max_adjusted = (k / self.virtual_sol_reserve as u128).saturating_sub(reserve_one);
+
+        // Largest tokens_in with floor(tokens_in * (10000 - fees) / 10000) <= max_adjusted
+        let fee_factor = 10000u128.checked_sub(fees_bps as u128).ok_or(CustomError::MathOverflow)?;
+        if fee_factor == 0 {
+            return Ok(u64::MAX);
+        }
+        let max_tokens = (max_adjusted + 1)
+            .checked_mul(10000)
+            .ok_or(CustomError::MathOverflow)?
+            .div_ceil(fee_factor)
+            - 1;
+        Ok(u64::try_from(max_tokens).unwrap_or(u64::MAX))
+    }
+
+    /// Tokens actually sold for a request of `amount`, capped at `max_sell_for_real_sol`.
+    /// A sell above the cap is rejected unless the trader allows a partial fill.
+    pub fn fillable_sell_amount(&self, amount: u64, fees_bps: u16, allow_partial_fill: bool) -> Result<u64> {
+        let max_sell = self.max_sell_for_real_sol(fees_bps)?;
+        if amount <= max_sell {
+            return Ok(amount);
+        }
+        require!(allow_partial_fill && max_sell > 0, CustomError::InsufficientPoolLiquidity);
+        msg!("Partial fill: {:?} of {:?} tokens", max_sell, amount);
+        Ok(max_sell)
+    }
+
+    /// SOL needed to buy exactly `tokens_out`, after trading fees (inverse of `quote_buy`,
+    /// rounded up so that `quote_buy` of the result yields at least `tokens_out`)
+    pub fn quote_buy_exact_out(&self, tokens_out: u64, fees_bps: u16) -> Result<u64> {
+        require!(tokens_out < self.reserve_one, CustomError::InsufficientFunds);
+
+        let reserve_one = self.reserve_one as u128;
+        let effective_sol = self.effective_sol_reserve() as u128;
+        let new_reserve_one = reserve_one - tokens_out as u128;
+
+        // adjusted_in = ceil(k / new_reserve_one) - effective_sol
+        let k = reserve_one.checked_mul(effective_sol).ok_or(CustomError::MathOverflow)?;
+        let adjusted_in = k
+            .div_ceil(new_reserve_one)
+            .checked_sub(effective_sol)
+            .ok_or(CustomError::MathOverflow)?;
+
+        // sol_in = ceil(adjusted_in * 10000 / (10000 - fees))
+        let fee_complement = 10000u128.checked_sub(fees_bps as u128).ok_or(CustomError::MathOverflow)?;
+        require!(fee_complement > 0, CustomError::InvalidFee);
+        let sol_in = adjusted_in
+            .checked_mul(10000)
+            .ok_or(CustomError::MathOverflow)?
+            .div_ceil(fee_complement);
+
+        u64::try_from(sol_in).map_err(|_| error!(CustomError::MathOverflow))
+    }
+
+    /// Price impact of moving the reserves to (`new_reserve_one`, `new_effective_sol`), in bps.
+    /// Spot price is effective_sol / reserve_one, so the ratio of new to old price is
+    /// (new_effective_sol * reserve_one) / (effective_sol * new_reserve_one).
+    pub fn price_impact_bps(&self, new_reserve_one: u64, new_effective_sol: u64) -> Result<u64> {
+        let numerator = (new_effective_sol as u128)
+            .checked_mul(self.reserve_one as u128)
+            .ok_or(CustomError::MathOverflow)?
+            .checked_mul(10000)
+            .ok_or(CustomError::MathOverflow)?;
+        let denominator = (self.effective_sol_reserve() as u128)
+            .checked_mul(new_reserve_one as u128)
+            .ok_or(CustomError::MathOverflow)?;
+
+        let price_ratio_bps = numerator.checked_div(denominator).ok_or(CustomError::MathOverflow)?;
+        Ok(price_ratio_bps.abs_diff(10000) as u64)
+    }
+
+    /// Price impact of buying `tokens_out` with `sol_in`, in bps
+    pub fn buy_price_impact_bps(&self, tokens_out: u64, sol_in: u64) -> Result<u64> {
+        let new_reserve_one = self.reserve_one.checked_sub(tokens_out).ok_or(CustomError::MathOverflow)?;
+        let new_effective_sol = self.effective_sol_reserve().checked_add(sol_in).ok_or(CustomError::MathOverflow)?;
+        self.price_impact_bps(new_reserve_one, new_effective_sol)
+    }
+
+    /// Price impact of selling `tokens_in` for `sol_out` leaving the pool, in bps
+    pub fn sell_price_impact_bps(&self, tokens_in: u64, sol_out: u64) -> Result<u64> {
+        let new_reserve_one = self.reserve_one.checked_add(tokens_in).ok_or(CustomError::MathOverflow)?;
+        let new_effective_sol = self.effective_sol_reserve().checked_sub(sol_out).ok_or(CustomError::MathOverflow)?;
+        self.price_impact_bps(new_reserve_one, new_effective_sol)
+    }
+
+    /// Reject a buy moving the price by more than `max_impact_bps`, when set
+    pub fn ensure_buy_impact_within(&self, tokens_out: u64, sol_in: u64, max_impact_bps: Option<u16>) -> Result<()> {
+        if let Some(max_impact) = max_impact_bps {
+            let price_impact_bps = self.buy_price_impact_bps(tokens_out, sol_in)?;
+            require!(price_impact_bps <= max_impact as u64, CustomError::PriceImpactTooHigh);
+        }
+        Ok(())
+    }
+
+    /// Reject a sell moving the price by more than `max_impact_bps`, when set
+    pub fn ensure_sell_impact_within(&self, tokens_in: u64, sol_out: u64, max_impact_bps: Option<u16>) -> Result<()> {
+        if let Some(max_impact) = max_impact_bps {
+            let price_impact_bps = self.sell_price_impact_bps(tokens_in, sol_out)?;
+            require!(price_impact_bps <= max_impact as u64, CustomError::PriceImpactTooHigh);
+        }
+        Ok(())
+    }
+
+    /// Update reserves after a buy: tokens leave the pool, SOL enters it
+    pub fn apply_buy(&mut self, tokens_out: u64, sol_in: u64) -> Result<()> {
+        self.reserve_one = self.reserve_one
+            .checked_sub(tokens_out)
+            .ok_or(CustomError::OverflowOrUnderflowOccurred)?;
+        self.reserve_two = self.reserve_two
+            .checked_add(sol_in)
+            .ok_or(CustomError::OverflowOrUnderflowOccurred)?;
+        Ok(())
+    }
+
+    /// Update reserves after a sell: tokens enter the pool, SOL (including any tax) leaves it
+    pub fn apply_sell(&mut self, tokens_in: u64, sol_out: u64) -> Result<()> {
+        self.reserve_one = self.reserve_one
+            .checked_add(tokens_in)
+            .ok_or(CustomError::OverflowOrUnderflowOccurred)?;
+        self.reserve_two = self.reserve_two
+            .checked_sub(sol_out)
+            .ok_or(CustomError::InsufficientPoolLiquidity)?;
+        Ok(())
+    }
+}
+
+/// PaperHandBitchTax owed on a sell: `tax_bps` of the proceeds when they are below the
+/// seller's cost basis, zero otherwise
+pub fn calculate_paperhand_tax(sol_out_before_tax: u64, cost_basis_for_sale: u64, tax_bps: u16) -> Result<u64> {
+    if sol_out_before_tax >= cost_basis_for_sale {
+        return Ok(0);
+    }
+
+    // tax = sol_out_before_tax * paperhand_tax_bps / 10000
+    let tax = (sol_out_before_tax as u128)
+        .checked_mul(tax_bps as u128)
+        .ok_or(CustomError::MathOverflow)?
+        .checked_div(10000_u128)
+        .ok_or(CustomError::MathOverflow)?;
+    Ok(tax as u64)
+}
+
+/// Minimum output for a partial fill of `filled` out of `requested`: `min_amount_out`
+/// scaled pro rata (rounded up), so the trader keeps the same minimum average price
+pub fn pro_rata_min_out(min_amount_out: u64, filled: u64, requested: u64) -> Result<u64> {
+    let min_out = (min_amount_out as u128)
+        .checked_mul(filled as u128)
+        .ok_or(CustomError::MathOverflow)?
+        .div_ceil(requested as u128);
+    u64::try_from(min_out).map_err(|_| CustomError::MathOverflow.into())
+}
+
+/// Referrer's slice of a trading fee: `referral_fee_bps` of `trading_fee`
+pub fn calculate_referral_fee(trading_fee: u64, referral_fee_bps: u16) -> Result<u64> {
+    let fee = (trading_fee as u128)
+        .checked_mul(referral_fee_bps as u128)
+        .ok_or(CustomError::MathOverflow)?
+        / 10000;
+    Ok(fee as u64)
 }
 
 pub trait LiquidityPoolAccount<'info> {
@@ -194,6 +1247,7 @@ pub trait LiquidityPoolAccount<'info> {
     fn update_reserves(&mut self, reserve_one: u64, reserve_two: u64) -> Result<()>;
 
     // Allows adding liquidity by depositing an amount of two tokens and getting back pool shares
+    #[allow(clippy::too_many_arguments)]
     fn add_liquidity(
         &mut self,
         token_one_accounts: (
@@ -232,6 +1286,7 @@ pub trait LiquidityPoolAccount<'info> {
         token_program: &Program<'info, Token>,
     ) -> Result<()>;
 
+    #[allow(clippy::too_many_arguments)]
     fn swap(
         &mut self,
         bonding_configuration_account: &Account<'info, CurveConfiguration>,
@@ -395,7 +1450,7 @@ impl<'info> LiquidityPoolAccount<'info> for Account<'info, LiquidityPool> {
             shares_to_allocate = cmp::min(shares_one, shares_two);
         }
 
-        if shares_to_allocate <= 0 {
+        if shares_to_allocate == 0 {
             return err!(CustomError::FailedToAddLiquidity);
         }
 
@@ -440,7 +1495,7 @@ impl<'info> LiquidityPoolAccount<'info> for Account<'info, LiquidityPool> {
         _authority: &Signer<'info>,
         _token_program: &Program<'info, Token>,
     ) -> Result<()> {
-        if shares <= 0 {
+        if shares == 0 {
             return err!(CustomError::FailedToRemoveLiquidity);
         }
 
@@ -464,7 +1519,7 @@ impl<'info> LiquidityPoolAccount<'info> for Account<'info, LiquidityPool> {
             .checked_div(self.total_supply)
             .ok_or(CustomError::OverflowOrUnderflowOccurred)?;
 
-        if amount_out_one <= 0 || amount_out_two <= 0 {
+        if amount_out_one == 0 || amount_out_two == 0 {
             return err!(CustomError::FailedToRemoveLiquidity);
         }
 
@@ -511,7 +1566,7 @@ impl<'info> LiquidityPoolAccount<'info> for Account<'info, LiquidityPool> {
         token_program: &Program<'info, Token>,
         system_program: &Program<'info, System>,
     ) -> Result<()> {
-        if amount <= 0 {
+        if amount == 0 {
             return err!(CustomError::InvalidAmount);
         }
         msg!("Mint: {:?} ", token_one_accounts.0.key());
@@ -798,4 +1853,202 @@ pub fn transfer_sol_to_pool<'info>(
         amount,
     )?;
     Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn config_change_with_every_field_fits_max_size() {
+        let change = ConfigChange {
+            fees: Some(u16::MAX),
+            treasury: Some(Pubkey::new_unique()),
+            paperhand_tax_bps: Some(u16::MAX),
+            config_timelock_secs: Some(i64::MAX),
+            pauser: Some(Pubkey::new_unique()),
+            admin: Some(Pubkey::new_unique()),
+            default_virtual_sol: Some(u64::MAX),
+            max_protection_window_secs: Some(i64::MAX),
+            min_protection_buy_sol: Some(u64::MAX),
+            max_creator_allocation_bps: Some(u16::MAX),
+            min_start_price: Some(u64::MAX),
+            max_start_price: Some(u64::MAX),
+            launch_fee_lamports: Some(u64::MAX),
+            symbol_policy: Some(SymbolPolicy::GloballyUnique),
+            referral_fee_bps: Some(u16::MAX),
+        };
+        let serialized = change.try_to_vec().unwrap();
+        assert_eq!(serialized.len(), ConfigChange::MAX_SIZE);
+    }
+
+    fn cleared_auction(total_committed: u64, tokens_sold: u64, sol_used: u64) -> FairLaunch {
+        FairLaunch {
+            commit_ends_at: 1,
+            max_tokens: tokens_sold,
+            total_committed,
+            tokens_sold,
+            sol_used,
+            cleared: true,
+            sol_settled: 0,
+        }
+    }
+
+    #[test]
+    fn fair_launch_oversubscribed_allocations_are_pro_rata() {
+        // 10 SOL committed, only 4 SOL could be spent before hitting max_tokens
+        let auction = cleared_auction(10_000_000_000, 1_000_000, 4_000_000_000);
+
+        let (tokens, sol_spent, refund) = auction.allocation_for(2_500_000_000).unwrap();
+        assert_eq!(tokens, 250_000);
+        assert_eq!(sol_spent, 1_000_000_000);
+        assert_eq!(refund, 1_500_000_000);
+
+        let (tokens, sol_spent, refund) = auction.allocation_for(7_500_000_000).unwrap();
+        assert_eq!(tokens, 750_000);
+        assert_eq!(sol_spent, 3_000_000_000);
+        assert_eq!(refund, 4_500_000_000);
+    }
+
+    #[test]
+    fn fair_launch_undersubscribed_allocations_spend_everything() {
+        let auction = cleared_auction(3_000, 900, 3_000);
+        assert_eq!(auction.allocation_for(1_000).unwrap(), (300, 1_000, 0));
+    }
+
+    #[test]
+    fn fair_launch_rounding_dust_stays_with_the_pool() {
+        // Three equal commitments that do not divide the clearing evenly
+        let auction = cleared_auction(3, 10, 2);
+        let allocations: Vec<_> = (0..3).map(|_| auction.allocation_for(1).unwrap()).collect();
+
+        // Tokens round down, SOL spent rounds up
+        for (tokens, sol_spent, refund) in &allocations {
+            assert_eq!((*tokens, *sol_spent, *refund), (3, 1, 0));
+        }
+        let tokens_paid: u64 = allocations.iter().map(|a| a.0).sum();
+        let sol_charged: u64 = allocations.iter().map(|a| a.1).sum();
+        assert!(tokens_paid <= auction.tokens_sold);
+        assert!(sol_charged >= auction.sol_used);
+    }
+
+    #[test]
+    fn positions_stay_open_until_every_commitment_is_settled() {
+        let mut pool = curve(800_000_000_000_000, 0, 30_000_000_000);
+        pool.fair_launch = cleared_auction(10_000_000_000, 1_000_000, 4_000_000_000);
+        assert!(!pool.fair_launch.blocks_trading());
+        assert!(pool.ensure_positions_closable(0).is_err());
+
+        pool.fair_launch.sol_settled = 9_000_000_000;
+        assert!(pool.ensure_positions_closable(0).is_err());
+
+        pool.fair_launch.sol_settled = 10_000_000_000;
+        assert!(pool.ensure_positions_closable(0).is_ok());
+    }
+
+    #[test]
+    fn fair_launch_allocation_requires_commitments() {
+        assert!(cleared_auction(0, 0, 0).allocation_for(1).is_err());
+    }
+
+    #[test]
+    fn vested_tokens_keep_losing_sells_taxed() {
+        // 6 decimals, launched at 0.001 SOL per token
+        let vesting = CreatorVesting {
+            pool: Pubkey::new_unique(),
+            mint: Pubkey::new_unique(),
+            creator: Pubkey::new_unique(),
+            total_amount: 9_000_000_000,
+            claimed_amount: 0,
+            start_ts: 0,
+            cliff_ts: 0,
+            end_ts: 1,
+            start_price: 1_000_000 * PRICE_SCALE as u64,
+            bump: 0,
+        };
+        let vested = vesting.total_amount;
+        let cost_basis = vesting.cost_basis_for(vested, 6).unwrap();
+        assert_eq!(cost_basis, 9_000_000_000);
+
+        // The creator bought 1,000 tokens for 10 SOL, then claimed 9,000 vested tokens
+        let mut position = UserPosition::new(vesting.pool, vesting.creator, 0);
+        position.record_buy(1_000_000_000, 10_000_000_000).unwrap();
+        position.record_buy(vested, cost_basis).unwrap();
+
+        // Selling 1,000 tokens for 1.5 SOL is below the 1.9 SOL basis, so the tax applies
+        let sale_basis = position.calculate_cost_basis_for_sale(1_000_000_000).unwrap();
+        assert_eq!(sale_basis, 1_900_000_000);
+        let tax = calculate_paperhand_tax(1_500_000_000, sale_basis, 5_000).unwrap();
+        assert!(tax > 0);
+
+        // At zero cost the basis would have been 1 SOL and the same sale untaxed
+        let mut zero_basis = UserPosition::new(vesting.pool, vesting.creator, 0);
+        zero_basis.record_buy(1_000_000_000, 10_000_000_000).unwrap();
+        zero_basis.record_buy(vested, 0).unwrap();
+        let zero_sale_basis = zero_basis.calculate_cost_basis_for_sale(1_000_000_000).unwrap();
+        assert_eq!(calculate_paperhand_tax(1_500_000_000, zero_sale_basis, 5_000).unwrap(), 0);
+    }
+
+    fn curve(reserve_one: u64, real_sol: u64, virtual_sol: u64) -> LiquidityPool {
+        let mut pool = LiquidityPool::new(Pubkey::new_unique(), 0, virtual_sol);
+        pool.reserve_one = reserve_one;
+        pool.reserve_two = real_sol;
+        pool
+    }
+
+    #[test]
+    fn max_sell_for_real_sol_is_the_largest_sell_the_reserve_pays() {
+        for (real_sol, fees) in [(5_000_000_000, 100), (1, 100), (0, 100), (5_000_000_000, 0)] {
+            let pool = curve(800_000_000_000_000, real_sol, 30_000_000_000);
+            let max_sell = pool.max_sell_for_real_sol(fees).unwrap();
+            assert!(pool.quote_sell(max_sell, fees).unwrap() <= real_sol);
+            assert!(pool.quote_sell(max_sell + 1, fees).unwrap() > real_sol);
+        }
+    }
+
+    #[test]
+    fn max_sell_for_real_sol_is_uncapped_without_virtual_sol_or_proceeds() {
+        let pool = curve(800_000_000_000_000, 5_000_000_000, 0);
+        assert_eq!(pool.max_sell_for_real_sol(100).unwrap(), u64::MAX);
+
+        // A 100% fee leaves no proceeds, so any sell is covered
+        let pool = curve(800_000_000_000_000, 0, 30_000_000_000);
+        assert_eq!(pool.max_sell_for_real_sol(10000).unwrap(), u64::MAX);
+        assert_eq!(pool.quote_sell(1_000_000_000, 10000).unwrap(), 0);
+    }
+
+    #[test]
+    fn quote_buy_exact_out_round_trips_through_quote_buy() {
+        let pool = curve(800_000_000_000_000, 5_000_000_000, 30_000_000_000);
+        for fees in [0, 100, 9999] {
+            for tokens_out in [1, 1_000_000, 123_456_789_000, 400_000_000_000_000, 790_000_000_000_000] {
+                let sol_in = pool.quote_buy_exact_out(tokens_out, fees).unwrap();
+                assert!(pool.quote_buy(sol_in, fees).unwrap() >= tokens_out);
+            }
+        }
+    }
+
+    #[test]
+    fn quote_buy_exact_out_rejects_unfillable_requests() {
+        let pool = curve(1_000_000, 0, 1_000);
+        // The whole reserve can never be bought out, but all of it but one unit can
+        assert!(pool.quote_buy_exact_out(1_000_000, 100).is_err());
+        let sol_in = pool.quote_buy_exact_out(999_999, 100).unwrap();
+        assert_eq!(pool.quote_buy(sol_in, 100).unwrap(), 999_999);
+        // A 100% fee leaves nothing to buy with
+        assert!(pool.quote_buy_exact_out(1, 10000).is_err());
+        // Nothing to buy from an empty reserve
+        assert!(curve(0, 0, 30_000_000_000).quote_buy_exact_out(0, 100).is_err());
+    }
+
+    #[test]
+    fn presale_must_end_within_the_trading_start_delay() {
+        let now = 1_700_000_000;
+        let presale = |ends_at| Presale { merkle_root: [1; 32], ends_at };
+        assert!(presale(now + 1).validate(now).is_ok());
+        assert!(presale(now + MAX_TRADING_START_DELAY_SECS).validate(now).is_ok());
+        assert!(presale(now + MAX_TRADING_START_DELAY_SECS + 1).validate(now).is_err());
+        assert!(presale(i64::MAX).validate(now).is_err());
+        assert!(presale(now).validate(now).is_err());
+    }
+}
@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use solana_keccak_hasher::hashv;
+
+/// Leaf of a presale allowlist: keccak256(wallet || allocation as u64 little-endian)
+pub fn presale_leaf(wallet: &Pubkey, allocation: u64) -> [u8; 32] {
+    hashv(&[wallet.as_ref(), &allocation.to_le_bytes()]).to_bytes()
+}
+
+/// Verify a Merkle proof using sorted-pair keccak256 hashing
+/// (each parent is keccak256(min(a, b) || max(a, b)))
+pub fn verify_proof(proof: &[[u8; 32]], root: &[u8; 32], leaf: [u8; 32]) -> bool {
+    let computed = proof.iter().fold(leaf, |node, sibling| {
+        if node <= *sibling {
+            hashv(&[&node, sibling]).to_bytes()
+        } else {
+            hashv(&[sibling, &node]).to_bytes()
+        }
+    });
+    computed == *root
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parent(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+        if a <= b {
+            hashv(&[a, b]).to_bytes()
+        } else {
+            hashv(&[b, a]).to_bytes()
+        }
+    }
+
+    /// Four-wallet allowlist
+    struct Allowlist {
+        entries: [(Pubkey, u64); 4],
+        leaves: [[u8; 32]; 4],
+        root: [u8; 32],
+    }
+
+    fn allowlist() -> Allowlist {
+        let entries = [1, 2, 3, 4].map(|i| (Pubkey::new_unique(), i * 1_000));
+        let leaves = entries.map(|(wallet, allocation)| presale_leaf(&wallet, allocation));
+        let root = parent(&parent(&leaves[0], &leaves[1]), &parent(&leaves[2], &leaves[3]));
+        Allowlist { entries, leaves, root }
+    }
+
+    #[test]
+    fn proofs_verify_for_every_leaf() {
+        let Allowlist { leaves, root, .. } = allowlist();
+        let left = parent(&leaves[0], &leaves[1]);
+        let right = parent(&leaves[2], &leaves[3]);
+        assert!(verify_proof(&[leaves[1], right], &root, leaves[0]));
+        assert!(verify_proof(&[leaves[0], right], &root, leaves[1]));
+        assert!(verify_proof(&[leaves[3], left], &root, leaves[2]));
+        assert!(verify_proof(&[leaves[2], left], &root, leaves[3]));
+    }
+
+    #[test]
+    fn pairs_are_hashed_sorted_not_in_tree_order() {
+        let Allowlist { leaves, .. } = allowlist();
+        let (low, high) = if leaves[0] <= leaves[1] { (leaves[0], leaves[1]) } else { (leaves[1], leaves[0]) };
+        // Concatenating in the other order gives a root no proof can reach
+        let unsorted_root = hashv(&[&high, &low]).to_bytes();
+        assert!(!verify_proof(&[high], &unsorted_root, low));
+        assert!(verify_proof(&[high], &parent(&high, &low), low));
+    }
+
+    #[test]
+    fn wrong_leaf_is_rejected() {
+        let Allowlist { entries, leaves, root } = allowlist();
+        let right = parent(&leaves[2], &leaves[3]);
+        // Another wallet, or the right wallet claiming a larger allocation
+        let stranger = presale_leaf(&Pubkey::new_unique(), entries[0].1);
+        let inflated = presale_leaf(&entries[0].0, entries[0].1 + 1);
+        assert!(!verify_proof(&[leaves[1], right], &root, stranger));
+        assert!(!verify_proof(&[leaves[1], right], &root, inflated));
+        // A valid leaf with another leaf's proof
+        assert!(!verify_proof(&[leaves[1], right], &root, leaves[2]));
+    }
+
+    #[test]
+    fn empty_proof_only_matches_a_single_leaf_root() {
+        let Allowlist { leaves, root, .. } = allowlist();
+        assert!(verify_proof(&[], &leaves[0], leaves[0]));
+        assert!(!verify_proof(&[], &root, leaves[0]));
+    }
+}
@@ -1,2 +1,5 @@
 pub mod calc;
+#[allow(unused_imports)]
 pub use calc::*;
+pub mod merkle;
+pub mod wsol;
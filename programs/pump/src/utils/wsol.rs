@@ -0,0 +1,130 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token::{self, CloseAccount, InitializeAccount3, SyncNative, TokenAccount, Transfer};
+
+use crate::consts::WSOL_TEMP_SEED_PREFIX;
+
+/// Accounts needed to unwrap a trader's wSOL
+pub struct UnwrapWsol<'a, 'info> {
+    pub user: &'a AccountInfo<'info>,
+    pub user_wsol_account: &'a AccountInfo<'info>,
+    /// Empty PDA `[WSOL_TEMP_SEED_PREFIX, user]`, created and closed here
+    pub temp_account: &'a AccountInfo<'info>,
+    pub temp_bump: u8,
+    pub native_mint: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+    pub token_program: &'a AccountInfo<'info>,
+}
+
+/// Turn `amount` of the user's wSOL into lamports on the user's wallet.
+///
+/// The wSOL is moved into a temporary native token account owned by the user, which is
+/// then closed to the user: they receive `amount` plus the temporary account's rent back.
+pub fn unwrap_wsol(accounts: UnwrapWsol<'_, '_>, amount: u64) -> Result<()> {
+    let user_key = accounts.user.key();
+    let temp_seeds: &[&[u8]] = &[
+        WSOL_TEMP_SEED_PREFIX.as_bytes(),
+        user_key.as_ref(),
+        &[accounts.temp_bump],
+    ];
+    let space = TokenAccount::LEN;
+    let rent = Rent::get()?.minimum_balance(space);
+
+    // Lamports sent to the address beforehand would make `create_account` fail, so fund,
+    // allocate and assign separately in that case (as Anchor's `init` does)
+    let current_lamports = accounts.temp_account.lamports();
+    if current_lamports == 0 {
+        system_program::create_account(
+            CpiContext::new_with_signer(
+                accounts.system_program.clone(),
+                system_program::CreateAccount {
+                    from: accounts.user.clone(),
+                    to: accounts.temp_account.clone(),
+                },
+                &[temp_seeds],
+            ),
+            rent,
+            space as u64,
+            &token::ID,
+        )?;
+    } else {
+        let top_up = rent.saturating_sub(current_lamports);
+        if top_up > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    accounts.system_program.clone(),
+                    system_program::Transfer {
+                        from: accounts.user.clone(),
+                        to: accounts.temp_account.clone(),
+                    },
+                ),
+                top_up,
+            )?;
+        }
+        system_program::allocate(
+            CpiContext::new_with_signer(
+                accounts.system_program.clone(),
+                system_program::Allocate {
+                    account_to_allocate: accounts.temp_account.clone(),
+                },
+                &[temp_seeds],
+            ),
+            space as u64,
+        )?;
+        system_program::assign(
+            CpiContext::new_with_signer(
+                accounts.system_program.clone(),
+                system_program::Assign {
+                    account_to_assign: accounts.temp_account.clone(),
+                },
+                &[temp_seeds],
+            ),
+            &token::ID,
+        )?;
+    }
+
+    token::initialize_account3(CpiContext::new(
+        accounts.token_program.clone(),
+        InitializeAccount3 {
+            account: accounts.temp_account.clone(),
+            mint: accounts.native_mint.clone(),
+            authority: accounts.user.clone(),
+        },
+    ))?;
+
+    token::transfer(
+        CpiContext::new(
+            accounts.token_program.clone(),
+            Transfer {
+                from: accounts.user_wsol_account.clone(),
+                to: accounts.temp_account.clone(),
+                authority: accounts.user.clone(),
+            },
+        ),
+        amount,
+    )?;
+
+    token::close_account(CpiContext::new(
+        accounts.token_program.clone(),
+        CloseAccount {
+            account: accounts.temp_account.clone(),
+            destination: accounts.user.clone(),
+            authority: accounts.user.clone(),
+        },
+    ))?;
+
+    Ok(())
+}
+
+/// Credit lamports already sent to a wSOL token account to its token balance
+pub fn sync_wsol<'info>(
+    token_program: &AccountInfo<'info>,
+    wsol_account: &AccountInfo<'info>,
+) -> Result<()> {
+    token::sync_native(CpiContext::new(
+        token_program.clone(),
+        SyncNative {
+            account: wsol_account.clone(),
+        },
+    ))
+}